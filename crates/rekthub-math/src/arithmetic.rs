@@ -0,0 +1,108 @@
+use crate::error::{MathError, Result};
+
+mod u256 {
+    #![allow(clippy::all)]
+
+    uint::construct_uint! {
+        pub struct U256(4);
+    }
+}
+
+pub use u256::U256;
+
+pub const WAD: u128 = 1_000_000_000_000_000_000;
+
+const LN2_WAD: u128 = 693_147_180_559_945_309;
+
+pub fn add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or(MathError::Overflow)
+}
+
+pub fn sub(a: u64, b: u64) -> Result<u64> {
+    a.checked_sub(b).ok_or(MathError::Overflow)
+}
+
+/// `a * b / c`, rounded down.
+pub fn mul_div_floor(a: u64, b: u64, c: u64) -> Result<u64> {
+    if c == 0 {
+        return Err(MathError::Overflow);
+    }
+
+    to_u64((a as u128) * (b as u128) / (c as u128))
+}
+
+/// `a * b / c`, rounded up.
+pub fn mul_div_ceil(a: u64, b: u64, c: u64) -> Result<u64> {
+    if c == 0 {
+        return Err(MathError::Overflow);
+    }
+
+    to_u64(((a as u128) * (b as u128)).div_ceil(c as u128))
+}
+
+pub fn div_ceil_wide(numerator: U256, denominator: U256) -> U256 {
+    let (quotient, remainder) = numerator.div_mod(denominator);
+    if remainder.is_zero() {
+        quotient
+    } else {
+        quotient + U256::one()
+    }
+}
+
+pub fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| MathError::Overflow)
+}
+
+pub fn to_u64_wide(value: U256) -> Result<u64> {
+    if value > U256::from(u64::MAX) {
+        return Err(MathError::Overflow);
+    }
+
+    Ok(value.as_u64())
+}
+
+/// `e^(x / WAD)` in WAD.
+pub fn exp_wad(x: u128) -> Result<u128> {
+    let n = x / LN2_WAD;
+    let r = x % LN2_WAD;
+
+    // Taylor series of e^r for r < ln(2)
+    let mut term = WAD;
+    let mut sum = WAD;
+    let mut i = 1;
+    while term > 0 {
+        term = term * r / (i * WAD);
+        sum += term;
+        i += 1;
+    }
+
+    if n >= sum.leading_zeros() as u128 {
+        return Err(MathError::Overflow);
+    }
+
+    Ok(sum << n)
+}
+
+/// `ln(x / WAD)` in WAD, for `x >= WAD`.
+pub fn ln_wad(x: u128) -> Result<u128> {
+    if x < WAD {
+        return Err(MathError::Overflow);
+    }
+
+    let n = 127 - (x / WAD).leading_zeros() as u128;
+    let z = x >> n;
+
+    // ln(z) = 2 * atanh((z - 1) / (z + 1)) for z in [1, 2)
+    let t = (z - WAD) * WAD / (z + WAD);
+    let t_squared = t * t / WAD;
+    let mut term = t;
+    let mut sum = 0;
+    let mut i = 1;
+    while term > 0 {
+        sum += term / i;
+        term = term * t_squared / WAD;
+        i += 2;
+    }
+
+    Ok(2 * sum + n * LN2_WAD)
+}
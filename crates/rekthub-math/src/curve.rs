@@ -0,0 +1,365 @@
+use crate::{
+    arithmetic::{add, mul_div_ceil, mul_div_floor, sub},
+    curves::{CurveModel, CurveReserves, PricingCurve, PRICE_PRECISION},
+    error::{MathError, Result},
+    fees::{FeeConfig, TradeFees, BPS_DENOMINATOR},
+    graduation::GraduationTrigger,
+};
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+/// Pricing state of a bonding curve, mirroring the program's `BondingCurve` account.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CurveState {
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub total_token_supply: u64,
+    pub curve_model: CurveModel,
+    pub graduation_trigger: GraduationTrigger,
+    /// SOL the curve must hold before any trigger but selling out can complete it, so the
+    /// migration can always pay its fee and create the pool.
+    pub min_graduation_sol_reserves: u64,
+    pub is_complete: bool,
+    pub created_slot: u64,
+}
+
+/// A trade priced against the current curve state, exactly as the trade instructions would
+/// execute it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(anchor_lang::AnchorSerialize, anchor_lang::AnchorDeserialize)
+)]
+pub struct TradeQuote {
+    /// Tokens bought or sold.
+    pub token_amount: u64,
+    /// Fee rate charged, from the fee schedule at the curve's progress before the trade.
+    pub fee_bps: u64,
+    /// On a buy `gross` is paid in and `net` enters the curve; on a sell `gross` leaves the
+    /// curve and `net` is paid out.
+    pub fees: TradeFees,
+    /// Unspent SOL returned by the buy that reaches the graduation threshold.
+    pub sol_refunded: u64,
+    /// Whether the curve is complete once the trade lands.
+    pub is_bonding_curve_complete: bool,
+}
+
+impl CurveState {
+    pub fn reserves(&self) -> CurveReserves {
+        CurveReserves {
+            virtual_sol_reserves: self.virtual_sol_reserves,
+            virtual_token_reserves: self.virtual_token_reserves,
+            real_token_reserves: self.real_token_reserves,
+            initial_real_token_reserves: self.initial_real_token_reserves,
+        }
+    }
+
+    /// Share of the real token reserves sold so far, in basis points.
+    pub fn progress_bps(&self) -> Result<u64> {
+        mul_div_floor(
+            self.reserves().tokens_sold()?,
+            BPS_DENOMINATOR,
+            self.initial_real_token_reserves,
+        )
+    }
+
+    /// Scheduled fee rate at the curve's progress, charged on sells.
+    pub fn fee_bps(&self, fee_config: &FeeConfig) -> Result<u64> {
+        fee_config.fee_schedule.fee_bps_at(self.progress_bps()?)
+    }
+
+    /// Fee rate charged for a buy landing in `slot`: the scheduled fee plus any launch window
+    /// surcharge, which only targets snipers buying in and never applies to sells.
+    pub fn buy_fee_bps(&self, fee_config: &FeeConfig, slot: u64) -> Result<u64> {
+        fee_config
+            .launch_window
+            .fee_bps_at(self.elapsed_slots(slot), self.fee_bps(fee_config)?)
+    }
+
+    pub fn elapsed_slots(&self, slot: u64) -> u64 {
+        slot.saturating_sub(self.created_slot)
+    }
+
+    /// Implied market cap in lamports: the spot price times the total token supply.
+    pub fn market_cap(&self) -> Result<u64> {
+        mul_div_floor(
+            self.curve_model.spot_price(&self.reserves())?,
+            self.total_token_supply,
+            PRICE_PRECISION,
+        )
+    }
+
+    /// Tokens that can still be bought before the graduation trigger fires and the curve holds
+    /// `min_graduation_sol_reserves`, at most the real token reserves. Buying exactly this many
+    /// completes the curve. A USD trigger must first be resolved with
+    /// `GraduationTrigger::resolve`.
+    pub fn tokens_to_graduation(&self) -> Result<u64> {
+        let tokens = match self.graduation_trigger {
+            GraduationTrigger::TokensSoldOut => self.real_token_reserves,
+            GraduationTrigger::RealSolReserves(target) => self.tokens_to_sol_reserves(target)?,
+            GraduationTrigger::MarketCap(target) => {
+                let price = mul_div_ceil(target, PRICE_PRECISION, self.total_token_supply)?;
+                self.curve_model.tokens_to_price(&self.reserves(), price)?
+            }
+            GraduationTrigger::MarketCapUsd(_) => return Err(MathError::PriceRequired),
+        };
+        let tokens = core::cmp::max(
+            tokens,
+            self.tokens_to_sol_reserves(self.min_graduation_sol_reserves)?,
+        );
+
+        Ok(core::cmp::min(tokens, self.real_token_reserves))
+    }
+
+    /// Tokens to buy before `real_sol_reserves` reaches `target`, rounded up to the first token
+    /// whose cost reaches it.
+    fn tokens_to_sol_reserves(&self, target: u64) -> Result<u64> {
+        let remaining = target.saturating_sub(self.real_sol_reserves);
+        if remaining == 0 {
+            return Ok(0);
+        }
+
+        let reserves = self.reserves();
+        let tokens = self.curve_model.buy_quote(&reserves, remaining)?;
+        if tokens < self.real_token_reserves
+            && (tokens == 0 || self.curve_model.buy_cost(&reserves, tokens)? < remaining)
+        {
+            Ok(tokens + 1)
+        } else {
+            Ok(tokens)
+        }
+    }
+
+    pub fn get_buy_price(&self, amount: u64) -> Result<u64> {
+        if self.is_complete {
+            return Err(MathError::CurveComplete);
+        }
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        self.curve_model.buy_quote(&self.reserves(), amount)
+    }
+
+    /// Inverse of `get_buy_price`: the smallest SOL input that buys at least `token_amount`.
+    pub fn get_buy_cost(&self, token_amount: u64) -> Result<u64> {
+        if self.is_complete {
+            return Err(MathError::CurveComplete);
+        }
+
+        if token_amount == 0 {
+            return Ok(0);
+        }
+
+        if token_amount > self.real_token_reserves {
+            return Err(MathError::InsufficientReserves);
+        }
+
+        self.curve_model.buy_cost(&self.reserves(), token_amount)
+    }
+
+    /// SOL the curve pays out for `amount` tokens, before fees.
+    pub fn get_sell_price(&self, amount: u64) -> Result<u64> {
+        if self.is_complete {
+            return Err(MathError::CurveComplete);
+        }
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        self.curve_model.sell_quote(&self.reserves(), amount)
+    }
+
+    /// Inverse of `get_sell_price`: the smallest token input that returns at least
+    /// `sol_amount` from the curve.
+    pub fn get_sell_cost(&self, sol_amount: u64) -> Result<u64> {
+        if self.is_complete {
+            return Err(MathError::CurveComplete);
+        }
+
+        if sol_amount == 0 {
+            return Ok(0);
+        }
+
+        if sol_amount > self.real_sol_reserves {
+            return Err(MathError::InsufficientReserves);
+        }
+
+        self.curve_model.sell_cost(&self.reserves(), sol_amount)
+    }
+
+    /// Prices a buy of `amount_in_sol` landing in `slot`, fee included. The buy that reaches the
+    /// graduation threshold is capped there and only charged for what it fills.
+    pub fn quote_buy(
+        &self,
+        fee_config: &FeeConfig,
+        amount_in_sol: u64,
+        has_referrer: bool,
+        slot: u64,
+    ) -> Result<TradeQuote> {
+        let fee_bps = self.buy_fee_bps(fee_config, slot)?;
+        let mut fees = fee_config.fees_from_gross(amount_in_sol, fee_bps, has_referrer)?;
+        let tokens_to_graduation = self.tokens_to_graduation()?;
+        let token_amount = core::cmp::min(self.get_buy_price(fees.net)?, tokens_to_graduation);
+
+        if token_amount == tokens_to_graduation {
+            let required_amount = self.get_buy_cost(token_amount)?;
+
+            if required_amount < fees.net {
+                fees = fee_config.fees_from_net(required_amount, fee_bps, has_referrer)?;
+            }
+        }
+
+        self.quote_after_buy(fees, fee_bps, token_amount, sub(amount_in_sol, fees.gross)?)
+    }
+
+    /// Prices a buy of exactly `token_amount`, fee included. Like `quote_buy`, the fill is capped
+    /// at the graduation threshold.
+    pub fn quote_buy_exact_tokens(
+        &self,
+        fee_config: &FeeConfig,
+        token_amount: u64,
+        has_referrer: bool,
+        slot: u64,
+    ) -> Result<TradeQuote> {
+        if token_amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        let token_amount = core::cmp::min(token_amount, self.tokens_to_graduation()?);
+        let fee_bps = self.buy_fee_bps(fee_config, slot)?;
+        let fees =
+            fee_config.fees_from_net(self.get_buy_cost(token_amount)?, fee_bps, has_referrer)?;
+
+        self.quote_after_buy(fees, fee_bps, token_amount, 0)
+    }
+
+    /// Prices a sell of `token_amount`, fee deducted from the proceeds.
+    pub fn quote_sell(
+        &self,
+        fee_config: &FeeConfig,
+        token_amount: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        if token_amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        let fee_bps = self.fee_bps(fee_config)?;
+        let fees = fee_config.fees_from_gross(
+            self.get_sell_price(token_amount)?,
+            fee_bps,
+            has_referrer,
+        )?;
+
+        self.quote_after_sell(fees, fee_bps, token_amount)
+    }
+
+    /// Prices a sell paying out exactly `sol_out` after fees.
+    pub fn quote_sell_exact_sol(
+        &self,
+        fee_config: &FeeConfig,
+        sol_out: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        if sol_out == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        let fee_bps = self.fee_bps(fee_config)?;
+        let fees = fee_config.fees_from_net(sol_out, fee_bps, has_referrer)?;
+        let token_amount = self.get_sell_cost(fees.gross)?;
+
+        self.quote_after_sell(fees, fee_bps, token_amount)
+    }
+
+    fn quote_after_buy(
+        &self,
+        fees: TradeFees,
+        fee_bps: u64,
+        token_amount: u64,
+        sol_refunded: u64,
+    ) -> Result<TradeQuote> {
+        let mut after = *self;
+        after.apply_buy(fees.net, token_amount)?;
+
+        Ok(TradeQuote {
+            token_amount,
+            fee_bps,
+            fees,
+            sol_refunded,
+            is_bonding_curve_complete: after.is_ready_for_graduation()?,
+        })
+    }
+
+    fn quote_after_sell(
+        &self,
+        fees: TradeFees,
+        fee_bps: u64,
+        token_amount: u64,
+    ) -> Result<TradeQuote> {
+        if self.real_sol_reserves < fees.gross {
+            return Err(MathError::InsufficientReserves);
+        }
+
+        let mut after = *self;
+        after.apply_sell(fees.gross, token_amount)?;
+
+        Ok(TradeQuote {
+            token_amount,
+            fee_bps,
+            fees,
+            sol_refunded: 0,
+            is_bonding_curve_complete: after.is_complete,
+        })
+    }
+
+    /// Books a buy of `tokens` for `sol` into the reserves.
+    pub fn apply_buy(&mut self, sol: u64, tokens: u64) -> Result<()> {
+        let k_before = self.constant_product();
+
+        self.virtual_sol_reserves = add(self.virtual_sol_reserves, sol)?;
+        self.real_sol_reserves = add(self.real_sol_reserves, sol)?;
+        self.virtual_token_reserves = sub(self.virtual_token_reserves, tokens)?;
+        self.real_token_reserves = sub(self.real_token_reserves, tokens)?;
+
+        self.check_invariant(k_before)
+    }
+
+    /// Books a sell of `tokens` for `sol` into the reserves.
+    pub fn apply_sell(&mut self, sol: u64, tokens: u64) -> Result<()> {
+        let k_before = self.constant_product();
+
+        self.virtual_sol_reserves = sub(self.virtual_sol_reserves, sol)?;
+        self.real_sol_reserves = sub(self.real_sol_reserves, sol)?;
+        self.virtual_token_reserves = add(self.virtual_token_reserves, tokens)?;
+        self.real_token_reserves = add(self.real_token_reserves, tokens)?;
+
+        self.check_invariant(k_before)
+    }
+
+    fn constant_product(&self) -> u128 {
+        (self.virtual_sol_reserves as u128) * (self.virtual_token_reserves as u128)
+    }
+
+    /// Rounding always favors the curve, so a constant-product trade can never shrink `k`.
+    fn check_invariant(&self, k_before: u128) -> Result<()> {
+        if self.curve_model == CurveModel::ConstantProduct && self.constant_product() < k_before {
+            return Err(MathError::CurveInvariantViolated);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_ready_for_graduation(&self) -> Result<bool> {
+        Ok(self.is_complete
+            || self.curve_model.is_complete(&self.reserves())
+            || self.tokens_to_graduation()? == 0)
+    }
+}
@@ -0,0 +1,670 @@
+use crate::{
+    arithmetic::{self, div_ceil_wide, exp_wad, ln_wad, to_u64, to_u64_wide, U256, WAD},
+    error::{MathError, Result},
+};
+
+// The Anchor derives expand to paths under `borsh`
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+/// Curve prices are lamports per token base unit, scaled by this factor.
+pub const PRICE_PRECISION: u64 = 1_000_000_000_000;
+
+/// Reserve snapshot the pricing models quote against.
+#[derive(Clone, Copy, Debug)]
+pub struct CurveReserves {
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_token_reserves: u64,
+    pub initial_real_token_reserves: u64,
+}
+
+impl CurveReserves {
+    pub fn tokens_sold(&self) -> Result<u64> {
+        arithmetic::sub(self.initial_real_token_reserves, self.real_token_reserves)
+    }
+}
+
+pub trait PricingCurve {
+    /// Tokens bought by `sol_amount` entering the curve, at most the real token reserves.
+    fn buy_quote(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64>;
+
+    /// Smallest SOL input that buys at least `token_amount`.
+    fn buy_cost(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64>;
+
+    /// SOL returned by the curve for `token_amount`, before fees.
+    fn sell_quote(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64>;
+
+    /// Smallest token input that returns at least `sol_amount`, before fees.
+    fn sell_cost(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64>;
+
+    /// Marginal price of the next token, scaled by `PRICE_PRECISION`.
+    fn spot_price(&self, reserves: &CurveReserves) -> Result<u64>;
+
+    /// Tokens still to be bought before the spot price reaches `price`, rounded up. Not capped
+    /// at the real token reserves, and `u64::MAX` when the curve can never reach `price`.
+    fn tokens_to_price(&self, reserves: &CurveReserves, price: u64) -> Result<u64>;
+
+    fn is_complete(&self, reserves: &CurveReserves) -> bool {
+        reserves.real_token_reserves == 0
+    }
+
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub enum CurveModel {
+    ConstantProduct,
+    Linear(LinearCurve),
+    Exponential(ExponentialCurve),
+}
+
+impl CurveModel {
+    fn pricing(&self) -> &dyn PricingCurve {
+        match self {
+            CurveModel::ConstantProduct => &ConstantProductCurve,
+            CurveModel::Linear(curve) => curve,
+            CurveModel::Exponential(curve) => curve,
+        }
+    }
+}
+
+impl PricingCurve for CurveModel {
+    fn buy_quote(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        self.pricing().buy_quote(reserves, sol_amount)
+    }
+
+    fn buy_cost(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        self.pricing().buy_cost(reserves, token_amount)
+    }
+
+    fn sell_quote(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        self.pricing().sell_quote(reserves, token_amount)
+    }
+
+    fn sell_cost(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        self.pricing().sell_cost(reserves, sol_amount)
+    }
+
+    fn spot_price(&self, reserves: &CurveReserves) -> Result<u64> {
+        self.pricing().spot_price(reserves)
+    }
+
+    fn tokens_to_price(&self, reserves: &CurveReserves, price: u64) -> Result<u64> {
+        self.pricing().tokens_to_price(reserves, price)
+    }
+
+    fn is_complete(&self, reserves: &CurveReserves) -> bool {
+        self.pricing().is_complete(reserves)
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.pricing().validate()
+    }
+}
+
+/// Virtual constant-product pricing, `virtual_sol_reserves * virtual_token_reserves = k`.
+pub struct ConstantProductCurve;
+
+impl PricingCurve for ConstantProductCurve {
+    fn buy_quote(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        let n: u128 =
+            (reserves.virtual_sol_reserves as u128) * (reserves.virtual_token_reserves as u128);
+        let i: u128 = (reserves.virtual_sol_reserves as u128) + (sol_amount as u128);
+        let r: u128 = n / i + 1;
+        let s: u128 = (reserves.virtual_token_reserves as u128).saturating_sub(r);
+
+        Ok(core::cmp::min(to_u64(s)?, reserves.real_token_reserves))
+    }
+
+    fn buy_cost(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        let n: u128 =
+            (reserves.virtual_sol_reserves as u128) * (reserves.virtual_token_reserves as u128);
+        let r: u128 = (reserves.virtual_token_reserves as u128)
+            .checked_sub(token_amount as u128)
+            .filter(|r| *r > 0)
+            .ok_or(MathError::InsufficientReserves)?;
+        let i: u128 = n / r + 1;
+        let s: u128 = i - (reserves.virtual_sol_reserves as u128);
+
+        to_u64(s)
+    }
+
+    fn sell_quote(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        let n: u128 = ((token_amount as u128) * (reserves.virtual_sol_reserves as u128))
+            / ((reserves.virtual_token_reserves as u128) + (token_amount as u128));
+
+        to_u64(n)
+    }
+
+    fn sell_cost(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        let n: u128 = (sol_amount as u128) * (reserves.virtual_token_reserves as u128);
+        let d: u128 = (reserves.virtual_sol_reserves as u128)
+            .checked_sub(sol_amount as u128)
+            .filter(|d| *d > 0)
+            .ok_or(MathError::InsufficientReserves)?;
+
+        to_u64(n.div_ceil(d))
+    }
+
+    fn spot_price(&self, reserves: &CurveReserves) -> Result<u64> {
+        arithmetic::mul_div_floor(
+            reserves.virtual_sol_reserves,
+            PRICE_PRECISION,
+            reserves.virtual_token_reserves,
+        )
+    }
+
+    fn tokens_to_price(&self, reserves: &CurveReserves, price: u64) -> Result<u64> {
+        if price == 0 {
+            return Ok(0);
+        }
+
+        // Spot price is `k / virtual_token_reserves^2`, so the target is reached once the
+        // virtual token reserves fall to `sqrt(k / price)`
+        let k =
+            U256::from(reserves.virtual_sol_reserves) * U256::from(reserves.virtual_token_reserves);
+        let target_token_reserves =
+            (k * U256::from(PRICE_PRECISION) / U256::from(price)).integer_sqrt();
+
+        to_u64_wide(
+            U256::from(reserves.virtual_token_reserves).saturating_sub(target_token_reserves),
+        )
+    }
+}
+
+/// Price rises linearly from `start_price` to `end_price` as the real token reserves are
+/// sold. Prices are lamports per token base unit, scaled by `PRICE_PRECISION`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub struct LinearCurve {
+    pub start_price: u64,
+    pub end_price: u64,
+}
+
+impl LinearCurve {
+    /// `(R, d, b)` where `d` is the price range and `b = R * price(tokens_sold)`.
+    fn terms(&self, reserves: &CurveReserves) -> Result<(U256, U256, U256)> {
+        let r = U256::from(reserves.initial_real_token_reserves);
+        let d = U256::from(self.end_price - self.start_price);
+        let b = U256::from(self.start_price) * r + d * U256::from(reserves.tokens_sold()?);
+
+        Ok((r, d, b))
+    }
+
+    /// Area under the price line between `s0` and `s1` tokens sold, as `(numerator, denominator)`
+    /// in lamports.
+    fn area(&self, reserves: &CurveReserves, s0: u64, s1: u64) -> (U256, U256) {
+        let r = U256::from(reserves.initial_real_token_reserves);
+        let d = U256::from(self.end_price - self.start_price);
+
+        let numerator = U256::from(s1 - s0)
+            * (U256::from(2) * U256::from(self.start_price) * r
+                + d * (U256::from(s0) + U256::from(s1)));
+        let denominator = U256::from(2) * r * U256::from(PRICE_PRECISION);
+
+        (numerator, denominator)
+    }
+}
+
+impl PricingCurve for LinearCurve {
+    fn buy_quote(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        let (r, d, b) = self.terms(reserves)?;
+        let c = U256::from(sol_amount) * U256::from(PRICE_PRECISION) * r;
+
+        let tokens = if d.is_zero() {
+            c / b
+        } else {
+            ((b * b + U256::from(2) * d * c).integer_sqrt() - b) / d
+        };
+
+        Ok(core::cmp::min(
+            to_u64_wide(core::cmp::min(tokens, U256::from(u64::MAX)))?,
+            reserves.real_token_reserves,
+        ))
+    }
+
+    fn buy_cost(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        let s0 = reserves.tokens_sold()?;
+        let (numerator, denominator) = self.area(reserves, s0, arithmetic::add(s0, token_amount)?);
+
+        to_u64_wide(div_ceil_wide(numerator, denominator))
+    }
+
+    fn sell_quote(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        let s1 = reserves.tokens_sold()?;
+        let s0 = s1
+            .checked_sub(token_amount)
+            .ok_or(MathError::InsufficientReserves)?;
+        let (numerator, denominator) = self.area(reserves, s0, s1);
+
+        to_u64_wide(numerator / denominator)
+    }
+
+    fn sell_cost(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        let (r, d, b) = self.terms(reserves)?;
+        let c = U256::from(sol_amount) * U256::from(PRICE_PRECISION) * r;
+
+        let tokens = if d.is_zero() {
+            div_ceil_wide(c, b)
+        } else {
+            let discriminant = (b * b)
+                .checked_sub(U256::from(2) * d * c)
+                .ok_or(MathError::InsufficientReserves)?;
+            div_ceil_wide(b - discriminant.integer_sqrt(), d)
+        };
+
+        let tokens = to_u64_wide(tokens)?;
+        if tokens > reserves.tokens_sold()? {
+            return Err(MathError::InsufficientReserves);
+        }
+
+        Ok(tokens)
+    }
+
+    fn spot_price(&self, reserves: &CurveReserves) -> Result<u64> {
+        let (r, _, b) = self.terms(reserves)?;
+
+        to_u64_wide(b / r)
+    }
+
+    fn tokens_to_price(&self, reserves: &CurveReserves, price: u64) -> Result<u64> {
+        if price <= self.start_price {
+            return Ok(0);
+        }
+
+        if self.end_price == self.start_price {
+            return Ok(u64::MAX);
+        }
+
+        let tokens_sold = div_ceil_wide(
+            U256::from(price - self.start_price) * U256::from(reserves.initial_real_token_reserves),
+            U256::from(self.end_price - self.start_price),
+        );
+
+        Ok(
+            to_u64_wide(core::cmp::min(tokens_sold, U256::from(u64::MAX)))?
+                .saturating_sub(reserves.tokens_sold()?),
+        )
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.start_price == 0 || self.end_price < self.start_price {
+            return Err(MathError::InvalidCurveParams);
+        }
+
+        Ok(())
+    }
+}
+
+/// Price grows exponentially from `start_price` to `end_price` as the real token reserves are
+/// sold. Prices are lamports per token base unit, scaled by `PRICE_PRECISION`.
+///
+/// Quotes go through fixed-point `exp`/`ln`, so each one gives up a single lamport to keep the
+/// rounding in the curve's favor.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub struct ExponentialCurve {
+    pub start_price: u64,
+    pub end_price: u64,
+}
+
+impl ExponentialCurve {
+    /// Growth rate `k = ln(end_price / start_price)` in WAD.
+    fn growth_rate(&self) -> Result<u128> {
+        ln_wad((self.end_price as u128) * WAD / (self.start_price as u128))
+    }
+
+    /// `exp(k * tokens_sold / R)` in WAD.
+    fn growth(&self, reserves: &CurveReserves, k: u128, tokens_sold: u64) -> Result<u128> {
+        let exponent = U256::from(k) * U256::from(tokens_sold)
+            / U256::from(reserves.initial_real_token_reserves);
+
+        exp_wad(exponent.as_u128())
+    }
+
+    /// `(lamports_per_growth, growth_per_lamport)`: a difference of growth values times the first
+    /// and divided by the second is lamports, and the inverse converts lamports back to growth.
+    fn scale(&self, reserves: &CurveReserves, k: u128) -> (U256, U256) {
+        let lamports_per_growth =
+            U256::from(self.start_price) * U256::from(reserves.initial_real_token_reserves);
+        let growth_per_lamport = U256::from(k) * U256::from(PRICE_PRECISION);
+
+        (lamports_per_growth, growth_per_lamport)
+    }
+
+    /// Tokens sold at which the growth value reaches `growth`, rounded down.
+    fn tokens_sold_at(&self, reserves: &CurveReserves, k: u128, growth: u128) -> Result<u64> {
+        let tokens = U256::from(ln_wad(growth)?) * U256::from(reserves.initial_real_token_reserves)
+            / U256::from(k);
+
+        to_u64_wide(tokens)
+    }
+}
+
+impl PricingCurve for ExponentialCurve {
+    fn buy_quote(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        let k = self.growth_rate()?;
+        let s0 = reserves.tokens_sold()?;
+        let (lamports_per_growth, growth_per_lamport) = self.scale(reserves, k);
+
+        let g0 = self.growth(reserves, k, s0)?;
+        let g_end = self.growth(reserves, k, reserves.initial_real_token_reserves)?;
+        let g1 = U256::from(g0)
+            + U256::from(sol_amount.saturating_sub(1)) * growth_per_lamport / lamports_per_growth;
+
+        if g1 >= U256::from(g_end) {
+            return Ok(reserves.real_token_reserves);
+        }
+
+        let s1 = self.tokens_sold_at(reserves, k, g1.as_u128())?;
+
+        Ok(core::cmp::min(
+            s1.saturating_sub(s0),
+            reserves.real_token_reserves,
+        ))
+    }
+
+    fn buy_cost(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        let k = self.growth_rate()?;
+        let s0 = reserves.tokens_sold()?;
+        let (lamports_per_growth, growth_per_lamport) = self.scale(reserves, k);
+
+        let g0 = self.growth(reserves, k, s0)?;
+        let g1 = self.growth(reserves, k, arithmetic::add(s0, token_amount)?)?;
+        let cost = div_ceil_wide(
+            U256::from(g1 - g0) * lamports_per_growth,
+            growth_per_lamport,
+        );
+
+        to_u64_wide(cost + U256::one())
+    }
+
+    fn sell_quote(&self, reserves: &CurveReserves, token_amount: u64) -> Result<u64> {
+        let k = self.growth_rate()?;
+        let s1 = reserves.tokens_sold()?;
+        let s0 = s1
+            .checked_sub(token_amount)
+            .ok_or(MathError::InsufficientReserves)?;
+        let (lamports_per_growth, growth_per_lamport) = self.scale(reserves, k);
+
+        let g0 = self.growth(reserves, k, s0)?;
+        let g1 = self.growth(reserves, k, s1)?;
+        let proceeds = U256::from(g1 - g0) * lamports_per_growth / growth_per_lamport;
+
+        Ok(to_u64_wide(proceeds)?.saturating_sub(1))
+    }
+
+    fn sell_cost(&self, reserves: &CurveReserves, sol_amount: u64) -> Result<u64> {
+        let k = self.growth_rate()?;
+        let s1 = reserves.tokens_sold()?;
+        let (lamports_per_growth, growth_per_lamport) = self.scale(reserves, k);
+
+        let g1 = self.growth(reserves, k, s1)?;
+        let delta = div_ceil_wide(
+            (U256::from(sol_amount) + U256::one()) * growth_per_lamport,
+            lamports_per_growth,
+        );
+        let g0 = U256::from(g1)
+            .checked_sub(delta)
+            .filter(|g0| *g0 >= U256::from(WAD))
+            .ok_or(MathError::InsufficientReserves)?;
+
+        let s0 = self.tokens_sold_at(reserves, k, g0.as_u128())?;
+
+        Ok(s1.saturating_sub(s0))
+    }
+
+    fn spot_price(&self, reserves: &CurveReserves) -> Result<u64> {
+        let growth = self.growth(reserves, self.growth_rate()?, reserves.tokens_sold()?)?;
+
+        to_u64_wide(U256::from(self.start_price) * U256::from(growth) / U256::from(WAD))
+    }
+
+    fn tokens_to_price(&self, reserves: &CurveReserves, price: u64) -> Result<u64> {
+        if price <= self.start_price {
+            return Ok(0);
+        }
+
+        if price > self.end_price {
+            return Ok(u64::MAX);
+        }
+
+        let k = self.growth_rate()?;
+        let tokens_sold = div_ceil_wide(
+            U256::from(ln_wad((price as u128) * WAD / (self.start_price as u128))?)
+                * U256::from(reserves.initial_real_token_reserves),
+            U256::from(k),
+        );
+
+        Ok(to_u64_wide(tokens_sold)?.saturating_sub(reserves.tokens_sold()?))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.start_price == 0 || self.end_price <= self.start_price {
+            return Err(MathError::InvalidCurveParams);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000 * 1_000_000;
+    const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+    /// Reserves after `tokens_sold`, with the virtual SOL reserves keeping the constant-product
+    /// invariant of a 30 SOL / 1.073B token launch.
+    fn reserves(tokens_sold: u64) -> CurveReserves {
+        let virtual_token_reserves = 1_073_000_000 * 1_000_000 - tokens_sold;
+        let k = 30 * LAMPORTS_PER_SOL as u128 * 1_073_000_000 * 1_000_000;
+
+        CurveReserves {
+            virtual_sol_reserves: (k / virtual_token_reserves as u128) as u64,
+            virtual_token_reserves,
+            real_token_reserves: INITIAL_REAL_TOKEN_RESERVES - tokens_sold,
+            initial_real_token_reserves: INITIAL_REAL_TOKEN_RESERVES,
+        }
+    }
+
+    impl CurveReserves {
+        fn after_buy(mut self, sol_amount: u64, token_amount: u64) -> Self {
+            self.virtual_sol_reserves += sol_amount;
+            self.virtual_token_reserves -= token_amount;
+            self.real_token_reserves -= token_amount;
+            self
+        }
+    }
+
+    fn models() -> [CurveModel; 3] {
+        [
+            CurveModel::ConstantProduct,
+            CurveModel::Linear(LinearCurve {
+                start_price: 28_000,
+                end_price: 400_000,
+            }),
+            CurveModel::Exponential(ExponentialCurve {
+                start_price: 28_000,
+                end_price: 1_000_000,
+            }),
+        ]
+    }
+
+    const SOL_AMOUNTS: [u64; 5] = [
+        1,
+        1_000_000,
+        LAMPORTS_PER_SOL / 10,
+        LAMPORTS_PER_SOL,
+        10 * LAMPORTS_PER_SOL,
+    ];
+
+    const TOKENS_SOLD: [u64; 3] = [
+        0,
+        INITIAL_REAL_TOKEN_RESERVES / 3,
+        INITIAL_REAL_TOKEN_RESERVES / 4 * 3,
+    ];
+
+    #[test]
+    fn buy_cost_inverts_buy_quote() {
+        for model in models() {
+            for tokens_sold in TOKENS_SOLD {
+                let reserves = reserves(tokens_sold);
+                for sol_amount in SOL_AMOUNTS {
+                    let tokens = model.buy_quote(&reserves, sol_amount).unwrap();
+                    if tokens == 0 {
+                        continue;
+                    }
+
+                    let cost = model.buy_cost(&reserves, tokens).unwrap();
+                    assert!(cost <= sol_amount, "{model:?}: {cost} > {sol_amount}");
+                    assert!(model.buy_quote(&reserves, cost).unwrap() >= tokens);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sell_cost_inverts_sell_quote() {
+        for model in models() {
+            for tokens_sold in &TOKENS_SOLD[1..] {
+                let reserves = reserves(*tokens_sold);
+                let curve_sol = model.sell_quote(&reserves, *tokens_sold).unwrap();
+                for sol_amount in SOL_AMOUNTS.into_iter().filter(|sol| *sol < curve_sol) {
+                    let tokens = model.sell_cost(&reserves, sol_amount).unwrap();
+                    assert!(model.sell_quote(&reserves, tokens).unwrap() >= sol_amount);
+
+                    let proceeds = model.sell_quote(&reserves, tokens).unwrap();
+                    assert!(model.sell_cost(&reserves, proceeds).unwrap() <= tokens);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_is_never_profitable() {
+        for model in models() {
+            for tokens_sold in TOKENS_SOLD {
+                let before = reserves(tokens_sold);
+                for sol_amount in SOL_AMOUNTS {
+                    let tokens = model.buy_quote(&before, sol_amount).unwrap();
+                    let after = before.after_buy(sol_amount, tokens);
+                    let proceeds = model.sell_quote(&after, tokens).unwrap();
+
+                    assert!(
+                        proceeds <= sol_amount,
+                        "{model:?}: {proceeds} > {sol_amount}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn buy_quote_is_capped_at_real_token_reserves() {
+        for model in models() {
+            let reserves = reserves(INITIAL_REAL_TOKEN_RESERVES - 1_000_000);
+            let tokens = model
+                .buy_quote(&reserves, 1_000 * LAMPORTS_PER_SOL)
+                .unwrap();
+
+            assert_eq!(tokens, reserves.real_token_reserves);
+        }
+    }
+
+    #[test]
+    fn spot_price_spans_start_to_end_price() {
+        let linear = LinearCurve {
+            start_price: 28_000,
+            end_price: 400_000,
+        };
+        let exponential = ExponentialCurve {
+            start_price: 28_000,
+            end_price: 1_000_000,
+        };
+
+        assert_eq!(linear.spot_price(&reserves(0)).unwrap(), 28_000);
+        assert_eq!(
+            linear
+                .spot_price(&reserves(INITIAL_REAL_TOKEN_RESERVES))
+                .unwrap(),
+            400_000
+        );
+        assert_eq!(exponential.spot_price(&reserves(0)).unwrap(), 28_000);
+
+        let end_price = exponential
+            .spot_price(&reserves(INITIAL_REAL_TOKEN_RESERVES))
+            .unwrap();
+        assert!(end_price.abs_diff(1_000_000) <= 1, "{end_price}");
+    }
+
+    #[test]
+    fn exp_and_ln_are_accurate() {
+        // Within 1e-15 relative, or 10 wei for results near zero, of the f64 result
+        fn assert_close(actual: u128, expected: f64) {
+            let error = ((actual as f64) - expected).abs();
+            assert!(
+                error <= (expected * 1e-15).max(10.0),
+                "{actual} vs {expected}: {error}"
+            );
+        }
+
+        const W: f64 = WAD as f64;
+
+        for x in [
+            0,
+            1_000_000_000,
+            WAD / 2,
+            WAD,
+            2 * WAD,
+            10 * WAD,
+            30 * WAD,
+            40 * WAD,
+        ] {
+            assert_close(exp_wad(x).unwrap(), (x as f64 / W).exp() * W);
+        }
+
+        for x in [
+            WAD + 1_000_000_000,
+            WAD * 3 / 2,
+            2 * WAD,
+            2_718_281_828_459_045_235,
+            WAD * 357 / 10,
+            WAD * 1_000_000,
+            WAD * 1_000_000_000_000,
+        ] {
+            assert_close(ln_wad(x).unwrap(), ((x - WAD) as f64 / W).ln_1p() * W);
+        }
+
+        assert_eq!(ln_wad(WAD).unwrap(), 0);
+        assert_eq!(exp_wad(0).unwrap(), WAD);
+        assert_eq!(ln_wad(WAD - 1), Err(MathError::Overflow));
+        assert_eq!(exp_wad(100 * WAD), Err(MathError::Overflow));
+    }
+}
@@ -0,0 +1,32 @@
+use core::fmt;
+
+pub type Result<T> = core::result::Result<T, MathError>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MathError {
+    Overflow,
+    InvalidAmount,
+    InvalidPercentage,
+    InsufficientReserves,
+    InvalidCurveParams,
+    CurveComplete,
+    CurveInvariantViolated,
+    PriceRequired,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            MathError::Overflow => "math overflow",
+            MathError::InvalidAmount => "invalid amount",
+            MathError::InvalidPercentage => "percentage must be between 0-10000 basis points",
+            MathError::InsufficientReserves => "insufficient reserves",
+            MathError::InvalidCurveParams => "curve parameters are out of bounds",
+            MathError::CurveComplete => "bonding curve is complete",
+            MathError::CurveInvariantViolated => "trade would decrease the curve invariant",
+            MathError::PriceRequired => "a SOL/USD price is required",
+        };
+
+        f.write_str(message)
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    arithmetic::{add, mul_div_ceil, mul_div_floor, sub},
+    error::{MathError, Result},
+};
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Fee owed on `amount` at `fee_bps`, rounded up so dust always goes to the protocol.
+pub fn fee_amount(amount: u64, fee_bps: u64) -> Result<u64> {
+    mul_div_ceil(amount, fee_bps, BPS_DENOMINATOR)
+}
+
+/// Smallest gross amount whose fee, taken at `fee_bps`, still leaves `net_amount`.
+pub fn gross_amount_with_fee(net_amount: u64, fee_bps: u64) -> Result<u64> {
+    mul_div_ceil(net_amount, BPS_DENOMINATOR, sub(BPS_DENOMINATOR, fee_bps)?)
+}
+
+/// Lowest acceptable output for `amount` quoted with a `basis_points` slippage tolerance.
+pub fn min_amount_with_slippage(amount: u64, basis_points: u64) -> Result<u64> {
+    if basis_points > BPS_DENOMINATOR {
+        return Err(MathError::InvalidPercentage);
+    }
+
+    sub(amount, mul_div_ceil(amount, basis_points, BPS_DENOMINATOR)?)
+}
+
+/// Trading fee that moves linearly with curve progress, from `start_fee_bps` on an untouched
+/// curve to `end_fee_bps` once `taper_progress_bps` of the real token reserves are sold. A high
+/// start tapering down deters snipers; `taper_progress_bps = 0` makes the fee flat.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub struct FeeSchedule {
+    pub start_fee_bps: u64,
+    pub end_fee_bps: u64,
+    pub taper_progress_bps: u64,
+}
+
+impl FeeSchedule {
+    pub fn flat(fee_bps: u64) -> Self {
+        FeeSchedule {
+            start_fee_bps: fee_bps,
+            end_fee_bps: fee_bps,
+            taper_progress_bps: 0,
+        }
+    }
+
+    /// Fee at `progress_bps` of the curve sold, rounded towards the higher fee.
+    pub fn fee_bps_at(&self, progress_bps: u64) -> Result<u64> {
+        if progress_bps >= self.taper_progress_bps {
+            return Ok(self.end_fee_bps);
+        }
+
+        if self.start_fee_bps >= self.end_fee_bps {
+            let decay = mul_div_floor(
+                self.start_fee_bps - self.end_fee_bps,
+                progress_bps,
+                self.taper_progress_bps,
+            )?;
+
+            sub(self.start_fee_bps, decay)
+        } else {
+            let growth = mul_div_ceil(
+                self.end_fee_bps - self.start_fee_bps,
+                progress_bps,
+                self.taper_progress_bps,
+            )?;
+
+            add(self.start_fee_bps, growth)
+        }
+    }
+
+    pub fn max_fee_bps(&self) -> u64 {
+        core::cmp::max(self.start_fee_bps, self.end_fee_bps)
+    }
+}
+
+/// Anti-sniper protection for the first `duration_slots` after a curve is created. The fee
+/// starts at `start_fee_bps` and decays linearly to the scheduled fee as the window closes,
+/// while buys are capped at `max_buy_sol` lamports and `max_buys_per_wallet` per wallet. A
+/// zero limit is not enforced and `duration_slots = 0` disables the window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub struct LaunchWindow {
+    pub duration_slots: u64,
+    pub start_fee_bps: u64,
+    pub max_buy_sol: u64,
+    pub max_buys_per_wallet: u64,
+}
+
+impl LaunchWindow {
+    pub fn disabled() -> Self {
+        LaunchWindow {
+            duration_slots: 0,
+            start_fee_bps: 0,
+            max_buy_sol: 0,
+            max_buys_per_wallet: 0,
+        }
+    }
+
+    pub fn is_active(&self, elapsed_slots: u64) -> bool {
+        elapsed_slots < self.duration_slots
+    }
+
+    /// Fee `elapsed_slots` after creation, never below `base_fee_bps` and rounded up.
+    pub fn fee_bps_at(&self, elapsed_slots: u64, base_fee_bps: u64) -> Result<u64> {
+        if !self.is_active(elapsed_slots) || self.start_fee_bps <= base_fee_bps {
+            return Ok(base_fee_bps);
+        }
+
+        let launch_fee = mul_div_ceil(
+            self.start_fee_bps - base_fee_bps,
+            self.duration_slots - elapsed_slots,
+            self.duration_slots,
+        )?;
+
+        add(base_fee_bps, launch_fee)
+    }
+}
+
+/// Trading fee schedule and how the fee is shared, as configured in the program's
+/// `GlobalConfig`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FeeConfig {
+    pub fee_schedule: FeeSchedule,
+    pub launch_window: LaunchWindow,
+    pub creator_fee_share_bps: u64,
+    pub referral_fee_share_bps: u64,
+}
+
+impl FeeConfig {
+    /// Fees at `fee_bps` for a trade that takes them out of `gross`: the SOL paid in on a buy,
+    /// or the curve's proceeds on a sell.
+    pub fn fees_from_gross(
+        &self,
+        gross: u64,
+        fee_bps: u64,
+        has_referrer: bool,
+    ) -> Result<TradeFees> {
+        let fee = fee_amount(gross, fee_bps)?;
+
+        self.trade_fees(gross, fee, has_referrer)
+    }
+
+    /// Fees at `fee_bps` for a trade that must leave exactly `net` once they are taken.
+    pub fn fees_from_net(&self, net: u64, fee_bps: u64, has_referrer: bool) -> Result<TradeFees> {
+        let gross = gross_amount_with_fee(net, fee_bps)?;
+
+        self.trade_fees(gross, sub(gross, net)?, has_referrer)
+    }
+
+    /// Splits `fee` between the creator, an optional referrer and the protocol. Shares round
+    /// down, so the protocol keeps any dust.
+    fn trade_fees(&self, gross: u64, fee: u64, has_referrer: bool) -> Result<TradeFees> {
+        let creator_fee = mul_div_floor(fee, self.creator_fee_share_bps, BPS_DENOMINATOR)?;
+        let referral_fee = if has_referrer {
+            mul_div_floor(fee, self.referral_fee_share_bps, BPS_DENOMINATOR)?
+        } else {
+            0
+        };
+
+        Ok(TradeFees {
+            gross,
+            fee,
+            net: sub(gross, fee)?,
+            creator_fee,
+            referral_fee,
+            protocol_fee: sub(sub(fee, creator_fee)?, referral_fee)?,
+        })
+    }
+}
+
+/// Lamport breakdown of a trade. `gross = net + fee` and
+/// `fee = creator_fee + referral_fee + protocol_fee` always hold exactly.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(anchor_lang::AnchorSerialize, anchor_lang::AnchorDeserialize)
+)]
+pub struct TradeFees {
+    pub gross: u64,
+    pub fee: u64,
+    pub net: u64,
+    pub creator_fee: u64,
+    pub referral_fee: u64,
+    pub protocol_fee: u64,
+}
@@ -0,0 +1,116 @@
+use crate::{
+    arithmetic::{div_ceil_wide, to_u64_wide, U256},
+    error::{MathError, Result},
+};
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+/// USD amounts are fixed-point with this many decimals.
+pub const USD_DECIMALS: i32 = 6;
+
+const SOL_DECIMALS: i32 = 9;
+
+/// Condition that completes a bonding curve. The buy that meets it is partially filled so the
+/// curve stops at the threshold rather than overshooting it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub enum GraduationTrigger {
+    /// Every real token has been bought.
+    #[default]
+    TokensSoldOut,
+    /// The curve holds at least this many lamports of real SOL.
+    RealSolReserves(u64),
+    /// The implied market cap, spot price times total supply, reaches this many lamports.
+    MarketCap(u64),
+    /// The implied market cap reaches this many USD, with `USD_DECIMALS` decimals, at the current
+    /// SOL/USD price.
+    MarketCapUsd(u64),
+}
+
+impl GraduationTrigger {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            GraduationTrigger::TokensSoldOut => Ok(()),
+            GraduationTrigger::RealSolReserves(0)
+            | GraduationTrigger::MarketCap(0)
+            | GraduationTrigger::MarketCapUsd(0) => Err(MathError::InvalidCurveParams),
+            GraduationTrigger::RealSolReserves(_)
+            | GraduationTrigger::MarketCap(_)
+            | GraduationTrigger::MarketCapUsd(_) => Ok(()),
+        }
+    }
+
+    /// Converts a USD target into lamports at `sol_usd_price`, so the curve only ever compares
+    /// lamports. Other triggers are returned unchanged.
+    pub fn resolve(&self, sol_usd_price: Option<&SolUsdPrice>) -> Result<GraduationTrigger> {
+        match self {
+            GraduationTrigger::MarketCapUsd(target) => {
+                let price = sol_usd_price.ok_or(MathError::PriceRequired)?;
+                Ok(GraduationTrigger::MarketCap(
+                    price.usd_to_lamports(*target)?,
+                ))
+            }
+            trigger => Ok(*trigger),
+        }
+    }
+}
+
+/// SOL/USD price as `price * 10^expo` dollars per SOL, as published by the oracle.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SolUsdPrice {
+    pub price: u64,
+    pub expo: i32,
+}
+
+impl SolUsdPrice {
+    /// USD value of `lamports`, rounded down.
+    pub fn lamports_to_usd(&self, lamports: u64) -> Result<u64> {
+        let value = U256::from(lamports) * U256::from(self.price);
+        let (scale, scale_up) = self.scale()?;
+
+        if scale_up {
+            to_u64_wide(value * scale)
+        } else {
+            to_u64_wide(value / scale)
+        }
+    }
+
+    /// Lamports worth at least `usd`, rounded up.
+    pub fn usd_to_lamports(&self, usd: u64) -> Result<u64> {
+        if self.price == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        let (scale, scale_up) = self.scale()?;
+
+        if scale_up {
+            to_u64_wide(div_ceil_wide(
+                U256::from(usd),
+                U256::from(self.price) * scale,
+            ))
+        } else {
+            to_u64_wide(div_ceil_wide(
+                U256::from(usd) * scale,
+                U256::from(self.price),
+            ))
+        }
+    }
+
+    /// Power of ten taking `lamports * price` to USD units, and whether it multiplies.
+    fn scale(&self) -> Result<(U256, bool)> {
+        let exponent = self.expo + USD_DECIMALS - SOL_DECIMALS;
+        if exponent.unsigned_abs() > 38 {
+            return Err(MathError::Overflow);
+        }
+
+        Ok((U256::exp10(exponent.unsigned_abs() as usize), exponent >= 0))
+    }
+}
@@ -0,0 +1,23 @@
+//! Pricing and fee math for RektHub bonding curves and their post-graduation pools.
+//!
+//! The program runs these exact functions on-chain, so off-chain clients that quote with this
+//! crate get the same results to the lamport. Only the `anchor` feature pulls in Solana
+//! dependencies, to serialize the types stored in program accounts.
+
+#![cfg_attr(not(feature = "anchor"), no_std)]
+
+pub mod arithmetic;
+pub mod curve;
+pub mod curves;
+pub mod error;
+pub mod fees;
+pub mod graduation;
+pub mod pool;
+
+pub use arithmetic::*;
+pub use curve::*;
+pub use curves::*;
+pub use error::*;
+pub use fees::*;
+pub use graduation::*;
+pub use pool::*;
@@ -0,0 +1,341 @@
+use crate::{
+    arithmetic::{add, mul_div_ceil, mul_div_floor, sub, to_u64_wide, U256},
+    curves::{ConstantProductCurve, CurveReserves, PricingCurve},
+    error::{MathError, Result},
+    fees::fee_amount,
+};
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+/// LP tokens a new pool issues but never mints, so its reserves can never be withdrawn to zero
+/// and deposits always have a ratio to price against.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+/// Reserves of the constant-product pool a graduated curve migrates into, mirroring the
+/// program's `Pool` account. Swaps are priced by the same `ConstantProductCurve` as the bonding
+/// curve, with the pool's real reserves standing in for the virtual ones.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PoolState {
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    /// LP tokens issued, including any burned or locked at migration and the never-minted
+    /// `MINIMUM_LIQUIDITY`.
+    pub lp_supply: u64,
+    /// Swap fee, taken in SOL and left in the pool for liquidity providers.
+    pub fee_bps: u64,
+}
+
+/// A swap priced against the pool, exactly as the `swap` instruction would execute it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(anchor_lang::AnchorSerialize, anchor_lang::AnchorDeserialize)
+)]
+pub struct PoolSwapQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// SOL kept by the pool.
+    pub fee: u64,
+}
+
+/// SOL, tokens and LP tokens moved by a deposit or withdrawal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(anchor_lang::AnchorSerialize, anchor_lang::AnchorDeserialize)
+)]
+pub struct LiquidityQuote {
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub lp_amount: u64,
+}
+
+impl PoolState {
+    /// Pool seeded with `sol_reserves` and `token_reserves`, issuing `sqrt(sol * tokens)` LP
+    /// tokens of which all but `MINIMUM_LIQUIDITY` are minted to the seeder.
+    pub fn new(sol_reserves: u64, token_reserves: u64, fee_bps: u64) -> Result<Self> {
+        let lp_supply =
+            to_u64_wide((U256::from(sol_reserves) * U256::from(token_reserves)).integer_sqrt())?;
+        if lp_supply <= MINIMUM_LIQUIDITY {
+            return Err(MathError::InvalidAmount);
+        }
+
+        Ok(Self {
+            sol_reserves,
+            token_reserves,
+            lp_supply,
+            fee_bps,
+        })
+    }
+
+    /// LP tokens minted to whoever seeded a pool created by `new`.
+    pub fn initial_lp_tokens(&self) -> Result<u64> {
+        sub(self.lp_supply, MINIMUM_LIQUIDITY)
+    }
+
+    fn reserves(&self) -> CurveReserves {
+        CurveReserves {
+            virtual_sol_reserves: self.sol_reserves,
+            virtual_token_reserves: self.token_reserves,
+            real_token_reserves: self.token_reserves,
+            initial_real_token_reserves: self.token_reserves,
+        }
+    }
+
+    /// Tokens bought by `sol_in`, with the fee taken out of the SOL paid in.
+    pub fn quote_buy(&self, sol_in: u64) -> Result<PoolSwapQuote> {
+        let fee = fee_amount(sol_in, self.fee_bps)?;
+        let amount_out = ConstantProductCurve.buy_quote(&self.reserves(), sub(sol_in, fee)?)?;
+        if amount_out == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        Ok(PoolSwapQuote {
+            amount_in: sol_in,
+            amount_out,
+            fee,
+        })
+    }
+
+    /// SOL returned for `tokens_in`, with the fee taken out of the pool's proceeds.
+    pub fn quote_sell(&self, tokens_in: u64) -> Result<PoolSwapQuote> {
+        let proceeds = ConstantProductCurve.sell_quote(&self.reserves(), tokens_in)?;
+        let fee = fee_amount(proceeds, self.fee_bps)?;
+        let amount_out = sub(proceeds, fee)?;
+        if amount_out == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        Ok(PoolSwapQuote {
+            amount_in: tokens_in,
+            amount_out,
+            fee,
+        })
+    }
+
+    /// Books a buy quoted by `quote_buy` into the reserves.
+    pub fn apply_buy(&mut self, quote: &PoolSwapQuote) -> Result<()> {
+        let k_before = self.constant_product();
+
+        self.sol_reserves = add(self.sol_reserves, quote.amount_in)?;
+        self.token_reserves = sub(self.token_reserves, quote.amount_out)?;
+
+        self.check_invariant(k_before)
+    }
+
+    /// Books a sell quoted by `quote_sell` into the reserves.
+    pub fn apply_sell(&mut self, quote: &PoolSwapQuote) -> Result<()> {
+        let k_before = self.constant_product();
+
+        self.sol_reserves = sub(self.sol_reserves, quote.amount_out)?;
+        self.token_reserves = add(self.token_reserves, quote.amount_in)?;
+
+        self.check_invariant(k_before)
+    }
+
+    /// Largest deposit of at most `max_sol` and `max_tokens` at the pool's current ratio.
+    /// Deposits round up and LP tokens round down, in the pool's favor.
+    pub fn quote_deposit(&self, max_sol: u64, max_tokens: u64) -> Result<LiquidityQuote> {
+        let lp_amount = core::cmp::min(
+            mul_div_floor(max_sol, self.lp_supply, self.sol_reserves)?,
+            mul_div_floor(max_tokens, self.lp_supply, self.token_reserves)?,
+        );
+        if lp_amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        Ok(LiquidityQuote {
+            sol_amount: mul_div_ceil(lp_amount, self.sol_reserves, self.lp_supply)?,
+            token_amount: mul_div_ceil(lp_amount, self.token_reserves, self.lp_supply)?,
+            lp_amount,
+        })
+    }
+
+    /// Share of the reserves `lp_amount` LP tokens redeem, rounded down. The
+    /// `MINIMUM_LIQUIDITY` share can never be redeemed.
+    pub fn quote_withdraw(&self, lp_amount: u64) -> Result<LiquidityQuote> {
+        if lp_amount == 0 || lp_amount > self.lp_supply.saturating_sub(MINIMUM_LIQUIDITY) {
+            return Err(MathError::InvalidAmount);
+        }
+
+        Ok(LiquidityQuote {
+            sol_amount: mul_div_floor(lp_amount, self.sol_reserves, self.lp_supply)?,
+            token_amount: mul_div_floor(lp_amount, self.token_reserves, self.lp_supply)?,
+            lp_amount,
+        })
+    }
+
+    pub fn apply_deposit(&mut self, quote: &LiquidityQuote) -> Result<()> {
+        self.sol_reserves = add(self.sol_reserves, quote.sol_amount)?;
+        self.token_reserves = add(self.token_reserves, quote.token_amount)?;
+        self.lp_supply = add(self.lp_supply, quote.lp_amount)?;
+
+        Ok(())
+    }
+
+    pub fn apply_withdraw(&mut self, quote: &LiquidityQuote) -> Result<()> {
+        self.sol_reserves = sub(self.sol_reserves, quote.sol_amount)?;
+        self.token_reserves = sub(self.token_reserves, quote.token_amount)?;
+        self.lp_supply = sub(self.lp_supply, quote.lp_amount)?;
+
+        Ok(())
+    }
+
+    fn constant_product(&self) -> u128 {
+        (self.sol_reserves as u128) * (self.token_reserves as u128)
+    }
+
+    /// Swap fees stay in the pool and rounding favors it, so a swap can never shrink `k`.
+    fn check_invariant(&self, k_before: u128) -> Result<()> {
+        if self.constant_product() < k_before {
+            return Err(MathError::CurveInvariantViolated);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+    /// 85 SOL and 206.9M tokens, at the 0.25% pool fee.
+    fn pool() -> PoolState {
+        PoolState::new(85 * LAMPORTS_PER_SOL, 206_900_000 * 1_000_000, 25).unwrap()
+    }
+
+    const SOL_AMOUNTS: [u64; 4] = [
+        1_000_000,
+        LAMPORTS_PER_SOL / 10,
+        LAMPORTS_PER_SOL,
+        50 * LAMPORTS_PER_SOL,
+    ];
+
+    const TOKEN_AMOUNTS: [u64; 4] = [
+        1_000_000,
+        1_000_000 * 1_000_000,
+        10_000_000 * 1_000_000,
+        500_000_000 * 1_000_000,
+    ];
+
+    /// Reserves backing each LP token, cross-multiplied so no division rounds.
+    fn value_per_lp_grew(before: &PoolState, after: &PoolState) -> bool {
+        (after.sol_reserves as u128) * (before.lp_supply as u128)
+            >= (before.sol_reserves as u128) * (after.lp_supply as u128)
+            && (after.token_reserves as u128) * (before.lp_supply as u128)
+                >= (before.token_reserves as u128) * (after.lp_supply as u128)
+    }
+
+    #[test]
+    fn new_reserves_minimum_liquidity() {
+        let pool = pool();
+        assert_eq!(
+            pool.initial_lp_tokens().unwrap(),
+            pool.lp_supply - MINIMUM_LIQUIDITY
+        );
+
+        assert_eq!(PoolState::new(0, 1, 25), Err(MathError::InvalidAmount));
+        assert_eq!(
+            PoolState::new(1_000, 1_000, 25),
+            Err(MathError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn swaps_never_shrink_k() {
+        for sol_in in SOL_AMOUNTS {
+            let mut pool = pool();
+            let k_before = pool.constant_product();
+            let quote = pool.quote_buy(sol_in).unwrap();
+            assert!(quote.fee > 0);
+            pool.apply_buy(&quote).unwrap();
+            assert!(pool.constant_product() >= k_before);
+        }
+
+        for tokens_in in TOKEN_AMOUNTS {
+            let mut pool = pool();
+            let k_before = pool.constant_product();
+            pool.apply_sell(&pool.quote_sell(tokens_in).unwrap())
+                .unwrap();
+            assert!(pool.constant_product() >= k_before);
+        }
+    }
+
+    #[test]
+    fn swap_round_trip_is_never_profitable() {
+        for sol_in in SOL_AMOUNTS {
+            let mut pool = pool();
+            let buy = pool.quote_buy(sol_in).unwrap();
+            pool.apply_buy(&buy).unwrap();
+            let sell = pool.quote_sell(buy.amount_out).unwrap();
+            assert!(sell.amount_out < sol_in);
+        }
+    }
+
+    #[test]
+    fn deposit_is_proportional_and_favors_the_pool() {
+        for sol in SOL_AMOUNTS {
+            let mut pool = pool();
+            let before = pool;
+            let quote = pool.quote_deposit(sol, u64::MAX).unwrap();
+            assert!(quote.sol_amount <= sol);
+            pool.apply_deposit(&quote).unwrap();
+            assert!(value_per_lp_grew(&before, &pool));
+
+            // Token-limited deposits take the tokens as the binding side
+            let quote = before.quote_deposit(1_000 * LAMPORTS_PER_SOL, sol).unwrap();
+            assert!(quote.token_amount <= sol);
+        }
+
+        assert_eq!(
+            pool().quote_deposit(0, u64::MAX),
+            Err(MathError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn withdraw_is_proportional_and_favors_the_pool() {
+        let pool = pool();
+        for lp_amount in [
+            1,
+            1_000_000,
+            pool.lp_supply / 3,
+            pool.initial_lp_tokens().unwrap(),
+        ] {
+            let mut after = pool;
+            after
+                .apply_withdraw(&pool.quote_withdraw(lp_amount).unwrap())
+                .unwrap();
+            assert!(value_per_lp_grew(&pool, &after));
+        }
+
+        assert_eq!(pool.quote_withdraw(0), Err(MathError::InvalidAmount));
+    }
+
+    #[test]
+    fn withdrawing_everything_leaves_minimum_liquidity() {
+        let mut pool = pool();
+        assert_eq!(
+            pool.quote_withdraw(pool.initial_lp_tokens().unwrap() + 1),
+            Err(MathError::InvalidAmount)
+        );
+
+        pool.apply_withdraw(
+            &pool
+                .quote_withdraw(pool.initial_lp_tokens().unwrap())
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pool.lp_supply, MINIMUM_LIQUIDITY);
+        assert!(pool.sol_reserves > 0 && pool.token_reserves > 0);
+        assert_eq!(pool.quote_withdraw(1), Err(MathError::InvalidAmount));
+
+        // The pool can still be priced and refilled
+        let quote = pool.quote_deposit(LAMPORTS_PER_SOL, u64::MAX).unwrap();
+        pool.apply_deposit(&quote).unwrap();
+        assert!(pool.quote_buy(LAMPORTS_PER_SOL / 10).is_ok());
+    }
+}
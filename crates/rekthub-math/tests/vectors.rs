@@ -0,0 +1,334 @@
+//! Fixed quote vectors, worked out independently of this crate with exact integer arithmetic.
+//! The program quotes with these same functions, so a change to any expected value here is a
+//! change to what trades execute on-chain.
+
+use rekthub_math::{
+    CurveModel, CurveState, FeeConfig, FeeSchedule, GraduationTrigger, LaunchWindow,
+    LiquidityQuote, PoolState, PoolSwapQuote, SolUsdPrice, TradeFees, TradeQuote,
+};
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+fn fee_config() -> FeeConfig {
+    FeeConfig {
+        fee_schedule: FeeSchedule::flat(100),
+        launch_window: LaunchWindow::disabled(),
+        creator_fee_share_bps: 2_000,
+        referral_fee_share_bps: 1_000,
+    }
+}
+
+/// Default migration fee plus the Raydium pool creation reserve.
+const MIGRATION_FLOOR: u64 = 3_200_000_000;
+
+/// Default launch: 30 SOL and 1.073B virtual tokens, 793.1M real tokens for sale.
+fn launch() -> CurveState {
+    CurveState {
+        virtual_sol_reserves: 30 * LAMPORTS_PER_SOL,
+        virtual_token_reserves: 1_073_000_000_000_000,
+        real_sol_reserves: 0,
+        real_token_reserves: 793_100_000_000_000,
+        initial_real_token_reserves: 793_100_000_000_000,
+        total_token_supply: 1_000_000_000_000_000,
+        curve_model: CurveModel::ConstantProduct,
+        graduation_trigger: GraduationTrigger::TokensSoldOut,
+        min_graduation_sol_reserves: MIGRATION_FLOOR,
+        is_complete: false,
+        created_slot: 0,
+    }
+}
+
+/// `launch` after the 1 SOL buy in `quote_buy_with_referrer`.
+fn after_first_buy() -> CurveState {
+    CurveState {
+        virtual_sol_reserves: 30_990_000_000,
+        virtual_token_reserves: 1_038_722_168_441_433,
+        real_sol_reserves: 990_000_000,
+        real_token_reserves: 758_822_168_441_433,
+        ..launch()
+    }
+}
+
+#[test]
+fn quote_buy_with_referrer() {
+    let quote = launch()
+        .quote_buy(&fee_config(), LAMPORTS_PER_SOL, true, 100)
+        .unwrap();
+
+    assert_eq!(
+        quote,
+        TradeQuote {
+            token_amount: 34_277_831_558_567,
+            fee_bps: 100,
+            fees: TradeFees {
+                gross: 1_000_000_000,
+                fee: 10_000_000,
+                net: 990_000_000,
+                creator_fee: 2_000_000,
+                referral_fee: 1_000_000,
+                protocol_fee: 7_000_000,
+            },
+            sol_refunded: 0,
+            is_bonding_curve_complete: false,
+        }
+    );
+
+    let mut after = launch();
+    after.apply_buy(quote.fees.net, quote.token_amount).unwrap();
+    assert_eq!(after, after_first_buy());
+}
+
+#[test]
+fn quote_buy_exact_tokens() {
+    let quote = launch()
+        .quote_buy_exact_tokens(&fee_config(), 10_000_000_000_000, false, 100)
+        .unwrap();
+
+    assert_eq!(
+        quote,
+        TradeQuote {
+            token_amount: 10_000_000_000_000,
+            fee_bps: 100,
+            fees: TradeFees {
+                gross: 285_070_841,
+                fee: 2_850_709,
+                net: 282_220_132,
+                creator_fee: 570_141,
+                referral_fee: 0,
+                protocol_fee: 2_280_568,
+            },
+            sol_refunded: 0,
+            is_bonding_curve_complete: false,
+        }
+    );
+}
+
+#[test]
+fn quote_sell() {
+    let quote = after_first_buy()
+        .quote_sell(&fee_config(), 17_138_915_779_283, false)
+        .unwrap();
+
+    assert_eq!(
+        quote,
+        TradeQuote {
+            token_amount: 17_138_915_779_283,
+            fee_bps: 100,
+            fees: TradeFees {
+                gross: 503_034_923,
+                fee: 5_030_350,
+                net: 498_004_573,
+                creator_fee: 1_006_070,
+                referral_fee: 0,
+                protocol_fee: 4_024_280,
+            },
+            sol_refunded: 0,
+            is_bonding_curve_complete: false,
+        }
+    );
+}
+
+#[test]
+fn quote_sell_exact_sol_with_referrer() {
+    let quote = after_first_buy()
+        .quote_sell_exact_sol(&fee_config(), LAMPORTS_PER_SOL / 10, true)
+        .unwrap();
+
+    assert_eq!(
+        quote,
+        TradeQuote {
+            token_amount: 3_396_725_905_587,
+            fee_bps: 100,
+            fees: TradeFees {
+                gross: 101_010_102,
+                fee: 1_010_102,
+                net: 100_000_000,
+                creator_fee: 202_020,
+                referral_fee: 101_010,
+                protocol_fee: 707_072,
+            },
+            sol_refunded: 0,
+            is_bonding_curve_complete: false,
+        }
+    );
+}
+
+#[test]
+fn quote_buy_partially_fills_the_final_buy() {
+    let curve = CurveState {
+        virtual_sol_reserves: 100 * LAMPORTS_PER_SOL,
+        virtual_token_reserves: 300_000_000_000_000,
+        real_sol_reserves: 70 * LAMPORTS_PER_SOL,
+        real_token_reserves: 1_000_000_000_000,
+        ..launch()
+    };
+
+    let quote = curve
+        .quote_buy(&fee_config(), 5 * LAMPORTS_PER_SOL, false, 100)
+        .unwrap();
+
+    assert_eq!(
+        quote,
+        TradeQuote {
+            token_amount: 1_000_000_000_000,
+            fee_bps: 100,
+            fees: TradeFees {
+                gross: 337_826_426,
+                fee: 3_378_265,
+                net: 334_448_161,
+                creator_fee: 675_653,
+                referral_fee: 0,
+                protocol_fee: 2_702_612,
+            },
+            sol_refunded: 4_662_173_574,
+            is_bonding_curve_complete: true,
+        }
+    );
+}
+
+#[test]
+fn launch_window_surcharges_buys_only() {
+    let fee_config = FeeConfig {
+        launch_window: LaunchWindow {
+            duration_slots: 100,
+            start_fee_bps: 5_000,
+            max_buy_sol: 0,
+            max_buys_per_wallet: 0,
+        },
+        ..fee_config()
+    };
+    let curve = CurveState {
+        created_slot: 1_000,
+        ..after_first_buy()
+    };
+
+    // Halfway through the window the surcharge over the 1% fee has decayed by half
+    let buy = curve
+        .quote_buy(&fee_config, LAMPORTS_PER_SOL, false, 1_050)
+        .unwrap();
+    assert_eq!(buy.fee_bps, 2_550);
+    assert_eq!(buy.fees.fee, 255_000_000);
+
+    let sell = curve
+        .quote_sell(&fee_config, 17_138_915_779_283, false)
+        .unwrap();
+    assert_eq!(sell.fee_bps, 100);
+    assert_eq!(sell.fees.fee, 5_030_350);
+
+    let buy = curve
+        .quote_buy(&fee_config, LAMPORTS_PER_SOL, false, 1_100)
+        .unwrap();
+    assert_eq!(buy.fee_bps, 100);
+}
+
+#[test]
+fn usd_target_below_launch_market_cap_waits_for_migration_floor() {
+    // $1,000 at $150 per SOL, far under the ~28 SOL market cap the curve launches at
+    let curve = CurveState {
+        graduation_trigger: GraduationTrigger::MarketCapUsd(1_000_000_000)
+            .resolve(Some(&SolUsdPrice {
+                price: 15_000_000_000,
+                expo: -8,
+            }))
+            .unwrap(),
+        ..launch()
+    };
+    assert!(curve.market_cap().unwrap() > 20 * LAMPORTS_PER_SOL);
+    assert!(!curve.is_ready_for_graduation().unwrap());
+
+    let tokens_to_graduation = curve.tokens_to_graduation().unwrap();
+    assert!(curve.get_buy_cost(tokens_to_graduation).unwrap() >= MIGRATION_FLOOR);
+
+    let quote = curve
+        .quote_buy(&fee_config(), 5 * LAMPORTS_PER_SOL, false, 100)
+        .unwrap();
+    assert_eq!(quote.token_amount, tokens_to_graduation);
+    assert!(quote.is_bonding_curve_complete);
+
+    let mut after = curve;
+    after.apply_buy(quote.fees.net, quote.token_amount).unwrap();
+    assert!(after.real_sol_reserves >= MIGRATION_FLOOR);
+    assert!(after.is_ready_for_graduation().unwrap());
+}
+
+#[test]
+fn fees_from_gross_and_net() {
+    assert_eq!(
+        fee_config()
+            .fees_from_gross(123_456_789, 137, true)
+            .unwrap(),
+        TradeFees {
+            gross: 123_456_789,
+            fee: 1_691_359,
+            net: 121_765_430,
+            creator_fee: 338_271,
+            referral_fee: 169_135,
+            protocol_fee: 1_183_953,
+        }
+    );
+
+    assert_eq!(
+        fee_config().fees_from_net(123_456_789, 137, false).unwrap(),
+        TradeFees {
+            gross: 125_171_641,
+            fee: 1_714_852,
+            net: 123_456_789,
+            creator_fee: 342_970,
+            referral_fee: 0,
+            protocol_fee: 1_371_882,
+        }
+    );
+}
+
+/// 85 SOL and 206.9M tokens, at the 0.25% pool fee.
+fn pool() -> PoolState {
+    PoolState::new(85 * LAMPORTS_PER_SOL, 206_900_000_000_000, 25).unwrap()
+}
+
+#[test]
+fn pool_new_issues_geometric_mean_lp() {
+    assert_eq!(pool().lp_supply, 4_193_626_115_904);
+    assert_eq!(pool().initial_lp_tokens().unwrap(), 4_193_626_114_904);
+}
+
+#[test]
+fn pool_swap_quotes() {
+    assert_eq!(
+        pool().quote_buy(LAMPORTS_PER_SOL).unwrap(),
+        PoolSwapQuote {
+            amount_in: 1_000_000_000,
+            amount_out: 2_399_869_182_243,
+            fee: 2_500_000,
+        }
+    );
+
+    assert_eq!(
+        pool().quote_sell(1_000_000_000_000).unwrap(),
+        PoolSwapQuote {
+            amount_in: 1_000_000_000_000,
+            amount_out: 407_828_281,
+            fee: 1_022_127,
+        }
+    );
+}
+
+#[test]
+fn pool_liquidity_quotes() {
+    assert_eq!(
+        pool().quote_deposit(LAMPORTS_PER_SOL, u64::MAX).unwrap(),
+        LiquidityQuote {
+            sol_amount: 1_000_000_000,
+            token_amount: 2_434_117_647_051,
+            lp_amount: 49_336_777_834,
+        }
+    );
+
+    assert_eq!(
+        pool().quote_withdraw(1_000_000_000).unwrap(),
+        LiquidityQuote {
+            sol_amount: 20_268_855,
+            token_amount: 49_336_777_834,
+            lp_amount: 1_000_000_000,
+        }
+    );
+}
@@ -6,4 +6,16 @@ pub const VIRTUAL_SOL_RESERVES: u64 = 30 * 1_000_000_000; // 30 SOL
 
 pub const REAL_TOKEN_RESERVES: u64 = 793_100_000 * 1_000_000; // 793.1M tokens
 
-pub const REAL_SOL_RESERVES: u64 = 0; // 0 SOL (0 lamports)
\ No newline at end of file
+pub const REAL_SOL_RESERVES: u64 = 0; // 0 SOL (0 lamports)
+
+pub const MIN_VIRTUAL_SOL_RESERVES: u64 = 1_000_000_000; // 1 SOL
+
+pub const MAX_VIRTUAL_SOL_RESERVES: u64 = 1_000 * 1_000_000_000; // 1,000 SOL
+
+pub const MIN_TOTAL_TOKEN_SUPPLY: u64 = 1_000_000 * 1_000_000; // 1M tokens
+
+pub const MAX_TOTAL_TOKEN_SUPPLY: u64 = 1_000_000_000_000 * 1_000_000; // 1T tokens
+
+pub const MIN_REAL_TOKEN_RESERVES_BPS: u64 = 5_000; // 50% of supply sold on the curve
+
+pub const MAX_REAL_TOKEN_RESERVES_BPS: u64 = 9_500; // 95% of supply sold on the curve
@@ -2,6 +2,16 @@ pub const TOKEN_INITIALISATION_FEE: u64 = 1_000_000_000 / 20; // 0.05 SOL
 
 pub const FEE_BPS: u64 = 100; // 1%
 
-pub const DEF_SLIPPAGE_BPS: u64 = 50; // 0.5%
+pub const CREATOR_FEE_SHARE_BPS: u64 = 2_000; // 20% of the trading fee
+
+pub const REFERRAL_FEE_SHARE_BPS: u64 = 1_000; // 10% of the trading fee
 
 pub const MIGRATION_FEE: u64 = 3_000_000_000; // 3 SOL
+
+pub const MIGRATION_REWARD: u64 = 10_000_000; // 0.01 SOL, paid to the `migrate` caller out of the migration fee
+
+pub const MAX_FEE_BPS: u64 = 1_000; // 10%
+
+pub const MAX_FEE_SHARE_BPS: u64 = 10_000; // 100%
+
+pub const MAX_LAUNCH_FEE_BPS: u64 = 9_000; // 90%, only while the launch window is open
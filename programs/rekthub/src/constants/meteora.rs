@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+pub const DAMM_PROGRAM_ID: Pubkey = pubkey!("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB");
+
+pub const VAULT_PROGRAM_ID: Pubkey = pubkey!("24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi");
+
+pub const VAULT_BASE: Pubkey = pubkey!("HWzXGcGHy4tcpYfaRDCyLNzXqBTv3E6BttpCH2vJxArv");
+
+// sha256("global:initialize_permissionless_constant_product_pool_with_config")[..8]
+pub const INITIALIZE_POOL_DISCRIMINATOR: [u8; 8] = [7, 166, 138, 171, 206, 171, 236, 244];
+
+// sha256("global:initialize")[..8], the vault program's `initialize`
+pub const INITIALIZE_VAULT_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+pub const LP_MINT_SEED: &str = "lp_mint";
+
+pub const VAULT_SEED: &str = "vault";
+
+pub const TOKEN_VAULT_SEED: &str = "token_vault";
+
+pub const FEE_SEED: &str = "fee";
+
+pub const POOL_CREATION_RESERVE: u64 = 100_000_000; // 0.1 SOL for the pool, LP mint, vault LP, fee account and any missing vault's rent
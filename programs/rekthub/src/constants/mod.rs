@@ -1,8 +1,13 @@
 pub mod bonding;
 pub mod fees;
+pub mod meteora;
+pub mod oracle;
+pub mod pool;
 pub mod seeds;
 pub mod raydium;
 
 pub use bonding::*;
 pub use fees::*;
+pub use oracle::*;
+pub use pool::*;
 pub use seeds::*;
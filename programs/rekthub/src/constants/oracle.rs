@@ -0,0 +1,71 @@
+// Pyth price account layout (v2)
+pub const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+
+pub const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+
+pub const PYTH_STATUS_TRADING: u32 = 1;
+
+pub const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+pub const PYTH_MAGIC_OFFSET: usize = 0;
+
+pub const PYTH_ACCOUNT_TYPE_OFFSET: usize = 8;
+
+pub const PYTH_EXPO_OFFSET: usize = 20;
+
+pub const PYTH_TIMESTAMP_OFFSET: usize = 96;
+
+pub const PYTH_AGG_PRICE_OFFSET: usize = 208;
+
+pub const PYTH_AGG_CONF_OFFSET: usize = 216;
+
+pub const PYTH_AGG_STATUS_OFFSET: usize = 224;
+
+// Field readers for a price account at least `PYTH_PRICE_ACCOUNT_MIN_LEN` bytes long
+pub fn pyth_magic(data: &[u8]) -> u32 {
+    read_u32(data, PYTH_MAGIC_OFFSET)
+}
+
+pub fn pyth_account_type(data: &[u8]) -> u32 {
+    read_u32(data, PYTH_ACCOUNT_TYPE_OFFSET)
+}
+
+pub fn pyth_expo(data: &[u8]) -> i32 {
+    read_i32(data, PYTH_EXPO_OFFSET)
+}
+
+pub fn pyth_timestamp(data: &[u8]) -> i64 {
+    read_i64(data, PYTH_TIMESTAMP_OFFSET)
+}
+
+pub fn pyth_agg_price(data: &[u8]) -> i64 {
+    read_i64(data, PYTH_AGG_PRICE_OFFSET)
+}
+
+pub fn pyth_agg_conf(data: &[u8]) -> u64 {
+    read_u64(data, PYTH_AGG_CONF_OFFSET)
+}
+
+pub fn pyth_agg_status(data: &[u8]) -> u32 {
+    read_u32(data, PYTH_AGG_STATUS_OFFSET)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_i32(data: &[u8], offset: usize) -> i32 {
+    i32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_i64(data: &[u8], offset: usize) -> i64 {
+    i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+pub const ORACLE_MAX_AGE_SECS: u64 = 60; // 1 minute
+
+pub const ORACLE_MAX_CONFIDENCE_BPS: u64 = 200; // 2% of the price
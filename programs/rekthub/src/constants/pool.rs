@@ -0,0 +1,3 @@
+pub const POOL_FEE_BPS: u64 = 25; // 0.25%, left in the pool for liquidity providers
+
+pub const POOL_LP_DECIMALS: u8 = 9;
@@ -9,3 +9,5 @@ pub const CREATE_POOL_FEE: u64 = 0;
 pub const CREATOR_FEE_RATE: u64 = 0;
 
 pub const POOL_INDEX: u16 = 0;
+
+pub const POOL_CREATION_RESERVE: u64 = 200_000_000; // 0.2 SOL for pool account rent and the create fee
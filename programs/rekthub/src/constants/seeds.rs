@@ -1,9 +1,23 @@
 pub const BONDING_CURVE: &str = "bonding_curve";
 
+pub const BUYER_RECORD: &str = "buyer_record";
+
+pub const CREATOR_FEE_VAULT: &str = "creator_fee_vault";
+
+pub const GLOBAL_CONFIG: &str = "global_config";
+
 pub const GLOBAL_FEE_VAULT: &str = "global_fee_vault";
 
+pub const LP_LOCK: &str = "lp_lock";
+
 pub const MIGRATION_AUTHORITY: &str = "migration_authority";
 
 pub const MINT_AUTHORITY: &str = "mint_authority";
 
 pub const METADATA: &str = "metadata";
+
+pub const POOL: &str = "pool";
+
+pub const POOL_LP_MINT: &str = "pool_lp_mint";
+
+pub const REFERRER_STATS: &str = "referrer_stats";
@@ -1,5 +1,69 @@
 use super::*;
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalConfig::INIT_SPACE + GlobalConfig::DISCRIMINATOR.len(),
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(constraint = rekthub_program.programdata_address()? == Some(program_data.key()))]
+    pub rekthub_program: Program<'info, crate::program::Rekthub>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ Errors::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump,
+        has_one = authority @ Errors::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub fee_schedule: FeeSchedule,
+    pub launch_window: LaunchWindow,
+    pub graduation_trigger: GraduationTrigger,
+    pub price_oracle: Pubkey,
+    pub oracle_max_age_secs: u64,
+    pub oracle_max_confidence_bps: u64,
+    pub creator_fee_share_bps: u64,
+    pub referral_fee_share_bps: u64,
+    pub token_initialisation_fee: u64,
+    pub migration_fee: u64,
+    pub migration_reward: u64,
+    pub lp_disposition: LpDisposition,
+    pub meteora_pool_config: Pubkey,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub timestamp: i64,
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -33,6 +97,12 @@ pub struct Initialize<'info> {
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     /// CHECK: validated elsewhere in the program
     #[account(
       mut,
@@ -41,6 +111,14 @@ pub struct Initialize<'info> {
     )]
     pub global_fee_vault: UncheckedAccount<'info>,
 
+    /// CHECK: Creator fee vault PDA for this mint
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_VAULT.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: UncheckedAccount<'info>,
+
     /// CHECK: Metadata account must be the correct PDA
     #[account(
         mut,
@@ -80,9 +158,37 @@ pub struct OnInitializeEvent {
     pub virtual_token_reserves: u64,
     pub real_sol_reserves: u64,
     pub real_token_reserves: u64,
+    pub total_token_supply: u64,
+    pub curve_model: CurveModel,
+    pub graduation_trigger: GraduationTrigger,
+    pub lp_disposition: LpDisposition,
+    pub migration_target: MigrationTarget,
+    pub created_slot: u64,
     pub timestamp: i64,
 }
 
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// CHECK: SOL/USD price account, pinned to `global_config.price_oracle` and parsed by
+    /// `utils::load_sol_usd_price`
+    #[account(address = global_config.price_oracle @ Errors::InvalidPriceOracle)]
+    pub price_oracle: Option<UncheckedAccount<'info>>,
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 pub struct Buy<'info> {
@@ -106,6 +212,12 @@ pub struct Buy<'info> {
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
         associated_token::mint = mint,
@@ -121,6 +233,36 @@ pub struct Buy<'info> {
     )]
     pub global_fee_vault: UncheckedAccount<'info>,
 
+    /// CHECK: Creator fee vault PDA for this mint
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_VAULT.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Referrer wallet, validated against `referrer_stats`
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Accrues the referral fee until the referrer claims it
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, ReferrerStats>>,
+
+    /// Only required while the launch window limits buys per wallet
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = BuyerRecord::INIT_SPACE + BuyerRecord::DISCRIMINATOR.len(),
+        seeds = [BUYER_RECORD.as_bytes(), mint.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_record: Option<Account<'info, BuyerRecord>>,
+
+    /// CHECK: SOL/USD price account, pinned to `global_config.price_oracle` and parsed by
+    /// `utils::load_sol_usd_price`
+    #[account(address = global_config.price_oracle @ Errors::InvalidPriceOracle)]
+    pub price_oracle: Option<UncheckedAccount<'info>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -132,8 +274,13 @@ pub struct OnBuyEvent {
     pub buyer: Pubkey,
     pub mint: Pubkey,
     pub sol_spent: u64,
+    pub sol_refunded: u64,
     pub tokens_received: u64,
+    pub fee_bps: u64,
     pub fee_paid: u64,
+    pub creator_fee: u64,
+    pub referrer: Option<Pubkey>,
+    pub referral_fee: u64,
     pub virtual_sol_reserves: u64,
     pub virtual_token_reserves: u64,
     pub real_sol_reserves: u64,
@@ -142,6 +289,22 @@ pub struct OnBuyEvent {
     pub timestamp: i64,
 }
 
+/// Emitted alongside `OnBuyEvent` by the buy that completes a curve.
+#[event]
+pub struct CurveCompleted {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub last_buyer: Pubkey,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub total_sol_raised: u64,
+    /// Implied market cap in USD at graduation, when the buy was given a SOL/USD price.
+    pub market_cap_usd: Option<u64>,
+    pub timestamp: i64,
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 pub struct Sell<'info> {
@@ -164,6 +327,12 @@ pub struct Sell<'info> {
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
         associated_token::mint = mint,
@@ -179,6 +348,21 @@ pub struct Sell<'info> {
     )]
     pub global_fee_vault: UncheckedAccount<'info>,
 
+    /// CHECK: Creator fee vault PDA for this mint
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_VAULT.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Referrer wallet, validated against `referrer_stats`
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Accrues the referral fee until the referrer claims it
+    #[account(mut)]
+    pub referrer_stats: Option<Account<'info, ReferrerStats>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
@@ -189,7 +373,11 @@ pub struct OnSellEvent {
     pub mint: Pubkey,
     pub tokens_sold: u64,
     pub sol_received: u64,
+    pub fee_bps: u64,
     pub fee_paid: u64,
+    pub creator_fee: u64,
+    pub referrer: Option<Pubkey>,
+    pub referral_fee: u64,
     pub virtual_sol_reserves: u64,
     pub virtual_token_reserves: u64,
     pub real_sol_reserves: u64,
@@ -331,6 +519,26 @@ pub struct InitializeRaydiumPool<'info> {
     )]
     pub bonding_curve: Box<Account<'info, BondingCurve>>,
 
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Only required when the curve's LP disposition is `Lock`
+    #[account(
+        init,
+        payer = signer,
+        space = LpLock::INIT_SPACE + LpLock::DISCRIMINATOR.len(),
+        seeds = [LP_LOCK.as_bytes(), mint_1.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: Option<Box<Account<'info, LpLock>>>,
+
+    /// CHECK: LP lock's associated token account - created once the pool has minted LP tokens
+    #[account(mut)]
+    pub lp_lock_token: Option<UncheckedAccount<'info>>,
+
     /// CHECK: Raydium program ID
     #[account(address = raydium_cp_swap::ID)]
     pub raydium_program: AccountInfo<'info>,
@@ -354,6 +562,7 @@ pub struct RaydiumPoolInitialized {
     pub initial_token_0_amount: u64,
     pub initial_token_1_amount: u64,
     pub lp_tokens_minted: u64,
+    pub lp_disposition: LpDisposition,
     pub timestamp: i64,
 }
 
@@ -389,6 +598,12 @@ pub struct PrepareCurveMigration<'info> {
     )]
     pub bonding_curve: Box<Account<'info, BondingCurve>>,
 
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
     #[account(
         mut,
         associated_token::mint = mint,
@@ -418,4 +633,789 @@ pub struct CurveMigrationPrepared {
     pub token_amount: u64,
     pub sol_amount: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+/// Curve, migration authority and token accounts shared by every DEX's `migrate` instruction.
+#[derive(Accounts)]
+pub struct MigrateCurve<'info> {
+    /// Anyone may crank a completed curve's migration, for `migration_reward`
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: Global fee vault PDA
+    #[account(
+        mut,
+        seeds = [GLOBAL_FEE_VAULT.as_bytes()],
+        bump
+    )]
+    pub global_fee_vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), mint_1.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_1,
+        associated_token::authority = bonding_curve,
+        associated_token::token_program = mint_1_program
+    )]
+    pub associated_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Migration authority PDA that creates the pool
+    #[account(
+        mut,
+        seeds = [MIGRATION_AUTHORITY.as_bytes(), mint_1.key().as_ref()],
+        bump
+    )]
+    pub migration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint_1,
+        associated_token::authority = migration_authority,
+        associated_token::token_program = mint_1_program
+    )]
+    pub associated_migration_authority: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint_0,
+        associated_token::authority = migration_authority,
+    )]
+    pub associated_mint_0: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = token::spl_token::native_mint::ID,
+        mint::token_program = mint_0_program,
+    )]
+    pub mint_0: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = mint_1_program)]
+    pub mint_1: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Only required when the curve's LP disposition is `Lock`
+    #[account(
+        init,
+        payer = signer,
+        space = LpLock::INIT_SPACE + LpLock::DISCRIMINATOR.len(),
+        seeds = [LP_LOCK.as_bytes(), mint_1.key().as_ref()],
+        bump
+    )]
+    pub lp_lock: Option<Box<Account<'info, LpLock>>>,
+
+    /// CHECK: LP lock's associated token account - created once the pool has minted LP tokens
+    #[account(mut)]
+    pub lp_lock_token: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub mint_0_program: Interface<'info, TokenInterface>,
+    pub mint_1_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    pub curve: MigrateCurve<'info>,
+
+    /// CHECK: Raydium AMM configuration PDA
+    #[account(
+        mut,
+        seeds = [
+            b"amm_config",
+            &constants::raydium::POOL_INDEX.to_le_bytes()
+        ],
+        bump,
+        seeds::program = raydium_cp_swap::ID,
+    )]
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium fee receiver account
+    #[account(
+        mut,
+        address = raydium_cp_swap::create_pool_fee_reveiver::ID,
+    )]
+    pub create_pool_fee: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium pool authority PDA
+    #[account(
+        mut,
+        seeds = [raydium_cp_swap::AUTH_SEED.as_bytes()],
+        bump,
+        seeds::program = raydium_cp_swap::ID,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium pool state PDA - will be created by Raydium
+    #[account(
+        mut,
+        seeds = [
+            raydium_cp_swap::states::POOL_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            curve.mint_0.key().as_ref(),
+            curve.mint_1.key().as_ref(),
+        ],
+        bump,
+        seeds::program = raydium_cp_swap::ID,
+        constraint = curve.mint_0.key() < curve.mint_1.key(),
+    )]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: LP mint - will be created by Raydium
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Migration authority's LP token account - will be created by Raydium
+    #[account(mut)]
+    pub creator_lp_token: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium token vault for mint_0
+    #[account(
+        mut,
+        seeds = [
+            raydium_cp_swap::states::POOL_VAULT_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            curve.mint_0.key().as_ref(),
+        ],
+        bump,
+        seeds::program = raydium_cp_swap::ID,
+    )]
+    pub mint_0_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium token vault for mint_1
+    #[account(
+        mut,
+        seeds = [
+            raydium_cp_swap::states::POOL_VAULT_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            curve.mint_1.key().as_ref(),
+        ],
+        bump,
+        seeds::program = raydium_cp_swap::ID,
+    )]
+    pub mint_1_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium oracle observations account
+    #[account(
+        mut,
+        seeds = [
+            raydium_cp_swap::states::OBSERVATION_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+        ],
+        bump,
+        seeds::program = raydium_cp_swap::ID,
+    )]
+    pub observation_state: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium program ID
+    #[account(address = raydium_cp_swap::ID)]
+    pub raydium_program: AccountInfo<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateMeteora<'info> {
+    pub curve: MigrateCurve<'info>,
+
+    /// CHECK: Meteora DAMM pool config pinned in the global config
+    #[account(
+        address = curve.global_config.meteora_pool_config @ Errors::MigrationTargetUnavailable,
+        owner = constants::meteora::DAMM_PROGRAM_ID,
+    )]
+    pub pool_config: UncheckedAccount<'info>,
+
+    /// CHECK: Meteora pool PDA - will be created by Meteora
+    #[account(
+        mut,
+        seeds = [
+            curve.mint_1.key().as_ref(),
+            curve.mint_0.key().as_ref(),
+            pool_config.key().as_ref(),
+        ],
+        bump,
+        seeds::program = constants::meteora::DAMM_PROGRAM_ID,
+        constraint = curve.mint_0.key() < curve.mint_1.key(),
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: Pool LP mint - will be created by Meteora
+    #[account(
+        mut,
+        seeds = [constants::meteora::LP_MINT_SEED.as_bytes(), pool.key().as_ref()],
+        bump,
+        seeds::program = constants::meteora::DAMM_PROGRAM_ID,
+    )]
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Migration authority's LP token account - will be created by Meteora
+    #[account(mut)]
+    pub payer_pool_lp: UncheckedAccount<'info>,
+
+    /// CHECK: Meteora vault for mint_1 - created by `migrate_meteora` when missing
+    #[account(
+        mut,
+        seeds = [
+            constants::meteora::VAULT_SEED.as_bytes(),
+            curve.mint_1.key().as_ref(),
+            constants::meteora::VAULT_BASE.as_ref(),
+        ],
+        bump,
+        seeds::program = constants::meteora::VAULT_PROGRAM_ID,
+    )]
+    pub a_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Meteora vault for mint_0 - created by `migrate_meteora` when missing
+    #[account(
+        mut,
+        seeds = [
+            constants::meteora::VAULT_SEED.as_bytes(),
+            curve.mint_0.key().as_ref(),
+            constants::meteora::VAULT_BASE.as_ref(),
+        ],
+        bump,
+        seeds::program = constants::meteora::VAULT_PROGRAM_ID,
+    )]
+    pub b_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Token account of the mint_1 vault - created with the vault
+    #[account(
+        mut,
+        seeds = [constants::meteora::TOKEN_VAULT_SEED.as_bytes(), a_vault.key().as_ref()],
+        bump,
+        seeds::program = constants::meteora::VAULT_PROGRAM_ID,
+    )]
+    pub a_token_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Token account of the mint_0 vault - created with the vault
+    #[account(
+        mut,
+        seeds = [constants::meteora::TOKEN_VAULT_SEED.as_bytes(), b_vault.key().as_ref()],
+        bump,
+        seeds::program = constants::meteora::VAULT_PROGRAM_ID,
+    )]
+    pub b_token_vault: UncheckedAccount<'info>,
+
+    /// CHECK: LP mint of the mint_1 vault - created with the vault
+    #[account(
+        mut,
+        seeds = [constants::meteora::LP_MINT_SEED.as_bytes(), a_vault.key().as_ref()],
+        bump,
+        seeds::program = constants::meteora::VAULT_PROGRAM_ID,
+    )]
+    pub a_vault_lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: LP mint of the mint_0 vault - created with the vault
+    #[account(
+        mut,
+        seeds = [constants::meteora::LP_MINT_SEED.as_bytes(), b_vault.key().as_ref()],
+        bump,
+        seeds::program = constants::meteora::VAULT_PROGRAM_ID,
+    )]
+    pub b_vault_lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Pool's share of the mint_1 vault - will be created by Meteora
+    #[account(
+        mut,
+        seeds = [a_vault.key().as_ref(), pool.key().as_ref()],
+        bump,
+        seeds::program = constants::meteora::DAMM_PROGRAM_ID,
+    )]
+    pub a_vault_lp: UncheckedAccount<'info>,
+
+    /// CHECK: Pool's share of the mint_0 vault - will be created by Meteora
+    #[account(
+        mut,
+        seeds = [b_vault.key().as_ref(), pool.key().as_ref()],
+        bump,
+        seeds::program = constants::meteora::DAMM_PROGRAM_ID,
+    )]
+    pub b_vault_lp: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol fee account for mint_1 - will be created by Meteora
+    #[account(
+        mut,
+        seeds = [
+            constants::meteora::FEE_SEED.as_bytes(),
+            curve.mint_1.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+        seeds::program = constants::meteora::DAMM_PROGRAM_ID,
+    )]
+    pub protocol_token_a_fee: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol fee account for mint_0 - will be created by Meteora
+    #[account(
+        mut,
+        seeds = [
+            constants::meteora::FEE_SEED.as_bytes(),
+            curve.mint_0.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        bump,
+        seeds::program = constants::meteora::DAMM_PROGRAM_ID,
+    )]
+    pub protocol_token_b_fee: UncheckedAccount<'info>,
+
+    /// CHECK: LP mint metadata - will be created by Meteora
+    #[account(
+        mut,
+        seeds = [METADATA.as_bytes(), MetadataProgram.as_ref(), lp_mint.key().as_ref()],
+        bump,
+        seeds::program = MetadataProgram,
+    )]
+    pub mint_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex token metadata program
+    #[account(address = MetadataProgram)]
+    pub metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: Meteora vault program
+    #[account(address = constants::meteora::VAULT_PROGRAM_ID)]
+    pub vault_program: UncheckedAccount<'info>,
+
+    /// CHECK: Meteora DAMM program
+    #[account(address = constants::meteora::DAMM_PROGRAM_ID)]
+    pub damm_program: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateToPool<'info> {
+    pub curve: MigrateCurve<'info>,
+
+    #[account(
+        init,
+        payer = curve.signer,
+        space = Pool::INIT_SPACE + Pool::DISCRIMINATOR.len(),
+        seeds = [POOL.as_bytes(), curve.mint_1.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init,
+        payer = curve.signer,
+        associated_token::mint = curve.mint_1,
+        associated_token::authority = pool,
+        associated_token::token_program = curve.mint_1_program
+    )]
+    pub associated_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = curve.signer,
+        seeds = [POOL_LP_MINT.as_bytes(), curve.mint_1.key().as_ref()],
+        bump,
+        mint::decimals = POOL_LP_DECIMALS,
+        mint::authority = pool,
+        mint::token_program = curve.token_program
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Receives the initial LP tokens before they are burned or locked
+    #[account(
+        init,
+        payer = curve.signer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = curve.migration_authority,
+        associated_token::token_program = curve.token_program
+    )]
+    pub migration_authority_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CurveMigrated {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub migration_target: MigrationTarget,
+    pub pool_state: Pubkey,
+    pub lp_mint: Pubkey,
+    pub cranker: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub migration_fee: u64,
+    pub cranker_reward: u64,
+    pub lp_tokens_minted: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_CONFIG.as_bytes()],
+        bump,
+        has_one = authority @ Errors::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Global fee vault PDA
+    #[account(
+        mut,
+        seeds = [GLOBAL_FEE_VAULT.as_bytes()],
+        bump
+    )]
+    pub global_fee_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub remaining_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), mint.key().as_ref()],
+        bump,
+        has_one = creator @ Errors::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    /// CHECK: Creator fee vault PDA for this mint
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_VAULT.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CreatorFeesClaimed {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlockLp<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [LP_LOCK.as_bytes(), mint.key().as_ref()],
+        bump,
+        has_one = beneficiary @ Errors::Unauthorized,
+        has_one = lp_mint
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = lp_lock
+    )]
+    pub lp_lock_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Unlocked LP can only go to the recorded beneficiary's own token account
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = lp_mint,
+        associated_token::authority = beneficiary
+    )]
+    pub beneficiary_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LpUnlocked {
+    pub mint: Pubkey,
+    pub lp_lock: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = ReferrerStats::INIT_SPACE + ReferrerStats::DISCRIMINATOR.len(),
+        seeds = [REFERRER_STATS.as_bytes(), referrer.key().as_ref()],
+        bump
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub referrer: Pubkey,
+    pub referrer_stats: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRER_STATS.as_bytes(), referrer.key().as_ref()],
+        bump,
+        has_one = referrer @ Errors::Unauthorized
+    )]
+    pub referrer_stats: Account<'info, ReferrerStats>,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    pub referrer_stats: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct QuotePool<'info> {
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [POOL.as_bytes(), mint.key().as_ref()],
+        bump,
+        has_one = mint
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [POOL.as_bytes(), mint.key().as_ref()],
+        bump,
+        has_one = mint
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub associated_pool: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub associated_user: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PoolSwapped {
+    pub mint: Pubkey,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub direction: SwapDirection,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [POOL.as_bytes(), mint.key().as_ref()],
+        bump,
+        has_one = mint,
+        has_one = lp_mint
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub associated_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+        associated_token::token_program = token_program
+    )]
+    pub associated_provider: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+        associated_token::token_program = lp_token_program
+    )]
+    pub provider_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub lp_token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub mint: Pubkey,
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub lp_amount: u64,
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    pub lp_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [POOL.as_bytes(), mint.key().as_ref()],
+        bump,
+        has_one = mint,
+        has_one = lp_mint
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub associated_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+        associated_token::token_program = token_program
+    )]
+    pub associated_provider: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+        associated_token::token_program = lp_token_program
+    )]
+    pub provider_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub lp_token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub mint: Pubkey,
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub lp_amount: u64,
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    pub lp_supply: u64,
+    pub timestamp: i64,
+}
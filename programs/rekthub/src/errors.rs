@@ -31,4 +31,82 @@ pub enum Errors {
 
     #[msg("Insufficient reserves")]
     InsufficientReserves,
+
+    #[msg("Signer is not authorized to perform this action")]
+    Unauthorized,
+
+    #[msg("Invalid global config parameters")]
+    InvalidConfig,
+
+    #[msg("Invalid referrer account")]
+    InvalidReferrer,
+
+    #[msg("Transaction deadline has passed")]
+    TransactionExpired,
+
+    #[msg("Curve parameters are out of bounds")]
+    InvalidCurveParams,
+
+    #[msg("Trade would decrease the curve invariant")]
+    CurveInvariantViolated,
+
+    #[msg("Buy exceeds the maximum size allowed during the launch window")]
+    LaunchWindowBuyTooLarge,
+
+    #[msg("Wallet has reached its buy limit for the launch window")]
+    LaunchWindowBuyLimitReached,
+
+    #[msg("A buyer record is required while the launch window limits buys per wallet")]
+    BuyerRecordRequired,
+
+    #[msg("A SOL/USD price oracle is required for this curve's graduation trigger")]
+    PriceOracleRequired,
+
+    #[msg("Price oracle account does not match the configured oracle or is malformed")]
+    InvalidPriceOracle,
+
+    #[msg("Price oracle is not publishing a trading price")]
+    PriceOracleUnavailable,
+
+    #[msg("Price oracle price is stale")]
+    PriceOracleStale,
+
+    #[msg("Price oracle confidence interval is too wide")]
+    PriceOracleConfidenceTooWide,
+
+    #[msg("Bonding curve migration has not been prepared")]
+    CurveMigrationNotPrepared,
+
+    #[msg("Bonding curve migration has already been prepared")]
+    CurveMigrationAlreadyPrepared,
+
+    #[msg("Invalid bonding curve status transition")]
+    InvalidCurveStatusTransition,
+
+    #[msg("LP lock accounts are required when the curve locks its LP tokens")]
+    LpLockRequired,
+
+    #[msg("LP tokens are still locked")]
+    LpStillLocked,
+
+    #[msg("Bonding curve migrates to a different DEX")]
+    WrongMigrationTarget,
+
+    #[msg("Migration target is not configured")]
+    MigrationTargetUnavailable,
+}
+
+impl From<MathError> for Errors {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::Overflow => Errors::MathOverflow,
+            MathError::InvalidAmount => Errors::InvalidAmount,
+            MathError::InvalidPercentage => Errors::InvalidPercentage,
+            MathError::InsufficientReserves => Errors::InsufficientReserves,
+            MathError::InvalidCurveParams => Errors::InvalidCurveParams,
+            MathError::CurveComplete => Errors::BondingCurveComplete,
+            MathError::CurveInvariantViolated => Errors::CurveInvariantViolated,
+            MathError::PriceRequired => Errors::PriceOracleRequired,
+        }
+    }
 }
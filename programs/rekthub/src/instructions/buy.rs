@@ -3,9 +3,49 @@ use super::*;
 pub fn buy_ix(
     ctx: Context<Buy>,
     amount_in_sol: u64,
-    slippage_basis_points: Option<u64>,
+    min_tokens_out: Option<u64>,
+    deadline: Option<TradeDeadline>,
 ) -> Result<()> {
-    let slippage_bps = slippage_basis_points.unwrap_or(DEF_SLIPPAGE_BPS);
+    utils::check_deadline(deadline)?;
+    let min_tokens_out = min_tokens_out.unwrap_or(0);
+
+    let referrer = utils::resolve_referrer(
+        &ctx.accounts.buyer.key(),
+        &ctx.accounts.referrer,
+        &ctx.accounts.referrer_stats,
+    )?;
+    let sol_usd_price =
+        utils::load_sol_usd_price(&ctx.accounts.price_oracle, &ctx.accounts.global_config)?;
+    let quote = ctx.accounts.bonding_curve.quote_buy(
+        &ctx.accounts.global_config,
+        amount_in_sol,
+        referrer.is_some(),
+        sol_usd_price.as_ref(),
+    )?;
+
+    if quote.token_amount < min_tokens_out {
+        return Err(error!(Errors::SlippageExceeded));
+    }
+
+    execute_buy(ctx, quote, referrer, sol_usd_price)
+}
+
+/// Moves tokens and SOL for a priced buy and updates the curve reserves.
+pub(crate) fn execute_buy(
+    ctx: Context<Buy>,
+    quote: TradeQuote,
+    referrer: Option<Pubkey>,
+    sol_usd_price: Option<SolUsdPrice>,
+) -> Result<()> {
+    let fees = quote.fees;
+
+    utils::check_launch_window(
+        &ctx.accounts.global_config.launch_window,
+        &ctx.accounts.bonding_curve,
+        fees.gross,
+        &ctx.accounts.buyer_record,
+    )?;
+
     let mint_key = ctx.accounts.mint.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
         BONDING_CURVE.as_bytes(),
@@ -13,25 +53,13 @@ pub fn buy_ix(
         &[ctx.bumps.bonding_curve],
     ]];
 
-    let fee = (amount_in_sol * FEE_BPS) / 10_000;
-    let net_amount = amount_in_sol - fee;
-
-    let tokens_to_receive = ctx.accounts.bonding_curve.get_buy_price(net_amount)?;
-
-    let min_tokens_expected =
-        utils::calculate_min_tokens_with_slippage(tokens_to_receive, slippage_bps);
-
-    if tokens_to_receive < min_tokens_expected {
-        return Err(error!(Errors::SlippageExceeded));
-    }
-
     utils::transfer_tokens(
         &ctx.accounts.associated_bonding_curve,
         &ctx.accounts.associated_user,
         &ctx.accounts.bonding_curve.to_account_info(),
         &ctx.accounts.mint,
         &ctx.accounts.token_program,
-        tokens_to_receive,
+        quote.token_amount,
         Some(&signer_seeds),
     )?;
 
@@ -39,37 +67,82 @@ pub fn buy_ix(
         &ctx.accounts.buyer.to_account_info(),
         &ctx.accounts.bonding_curve.to_account_info(),
         &ctx.accounts.system_program.to_account_info(),
-        net_amount,
+        fees.net,
         None,
     )?;
 
-    utils::transfer_sol(
-        &ctx.accounts.buyer.to_account_info(),
-        &ctx.accounts.global_fee_vault.to_account_info(),
-        &ctx.accounts.system_program.to_account_info(),
-        fee,
-        None,
-    )?;
+    if fees.protocol_fee > 0 {
+        utils::transfer_sol(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.global_fee_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            fees.protocol_fee,
+            None,
+        )?;
+    }
+
+    if fees.creator_fee > 0 {
+        utils::transfer_sol(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.creator_fee_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            fees.creator_fee,
+            None,
+        )?;
+    }
+
+    if let Some(referrer_stats) = &mut ctx.accounts.referrer_stats {
+        if fees.referral_fee > 0 {
+            utils::transfer_sol(
+                &ctx.accounts.buyer.to_account_info(),
+                &referrer_stats.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                fees.referral_fee,
+                None,
+            )?;
+        }
+
+        referrer_stats.record_trade(fees.gross, fees.referral_fee)?;
+    }
+
+    if let Some(buyer_record) = &mut ctx.accounts.buyer_record {
+        buyer_record.buyer = ctx.accounts.buyer.key();
+        buyer_record.mint = ctx.accounts.mint.key();
+        buyer_record.record_buy()?;
+    }
+
+    ctx.accounts
+        .bonding_curve
+        .apply_buy(fees.net, quote.token_amount)?;
+    let is_bonding_curve_complete = ctx
+        .accounts
+        .bonding_curve
+        .is_ready_for_graduation(sol_usd_price.as_ref())?;
+    if is_bonding_curve_complete {
+        ctx.accounts
+            .bonding_curve
+            .transition_to(CurveStatus::Complete)?;
+    }
 
-    ctx.accounts.bonding_curve.virtual_sol_reserves += net_amount;
-    ctx.accounts.bonding_curve.real_token_reserves -= tokens_to_receive;
-    ctx.accounts.bonding_curve.virtual_token_reserves -= tokens_to_receive;
-    ctx.accounts.bonding_curve.real_sol_reserves += net_amount;
-    ctx.accounts.bonding_curve.is_bonding_curve_complete =
-        ctx.accounts.bonding_curve.is_ready_for_graduation()?;
+    let timestamp = Clock::get()?.unix_timestamp;
 
     let event = OnBuyEvent {
         buyer: ctx.accounts.buyer.key(),
         mint: ctx.accounts.mint.key(),
-        sol_spent: amount_in_sol,
-        tokens_received: tokens_to_receive,
-        fee_paid: fee,
+        sol_spent: fees.gross,
+        sol_refunded: quote.sol_refunded,
+        tokens_received: quote.token_amount,
+        fee_bps: quote.fee_bps,
+        fee_paid: fees.fee,
+        creator_fee: fees.creator_fee,
+        referrer,
+        referral_fee: fees.referral_fee,
         virtual_sol_reserves: ctx.accounts.bonding_curve.virtual_sol_reserves,
         virtual_token_reserves: ctx.accounts.bonding_curve.virtual_token_reserves,
         real_sol_reserves: ctx.accounts.bonding_curve.real_sol_reserves,
         real_token_reserves: ctx.accounts.bonding_curve.real_token_reserves,
-        is_bonding_curve_complete: ctx.accounts.bonding_curve.is_bonding_curve_complete,
-        timestamp: Clock::get()?.unix_timestamp,
+        is_bonding_curve_complete,
+        timestamp,
     };
 
     emit_cpi!(event);
@@ -77,5 +150,34 @@ pub fn buy_ix(
     // Fallback event emitter
     emit!(event);
 
+    if is_bonding_curve_complete {
+        let bonding_curve = &ctx.accounts.bonding_curve;
+        let market_cap_usd = match sol_usd_price {
+            Some(price) => Some(bonding_curve.market_cap_usd(&price)?),
+            None => None,
+        };
+
+        let event = CurveCompleted {
+            mint: ctx.accounts.mint.key(),
+            bonding_curve: bonding_curve.key(),
+            last_buyer: ctx.accounts.buyer.key(),
+            virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+            virtual_token_reserves: bonding_curve.virtual_token_reserves,
+            real_sol_reserves: bonding_curve.real_sol_reserves,
+            real_token_reserves: bonding_curve.real_token_reserves,
+            total_sol_raised: bonding_curve
+                .real_sol_reserves
+                .checked_sub(REAL_SOL_RESERVES)
+                .ok_or(Errors::MathOverflow)?,
+            market_cap_usd,
+            timestamp,
+        };
+
+        emit_cpi!(event);
+
+        // Fallback event emitter
+        emit!(event);
+    }
+
     Ok(())
 }
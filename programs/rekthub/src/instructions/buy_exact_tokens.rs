@@ -0,0 +1,31 @@
+use super::*;
+
+pub fn buy_exact_tokens_ix(
+    ctx: Context<Buy>,
+    token_amount: u64,
+    max_sol_cost: u64,
+    deadline: Option<TradeDeadline>,
+) -> Result<()> {
+    utils::check_deadline(deadline)?;
+
+    let referrer = utils::resolve_referrer(
+        &ctx.accounts.buyer.key(),
+        &ctx.accounts.referrer,
+        &ctx.accounts.referrer_stats,
+    )?;
+
+    let sol_usd_price =
+        utils::load_sol_usd_price(&ctx.accounts.price_oracle, &ctx.accounts.global_config)?;
+    let quote = ctx.accounts.bonding_curve.quote_buy_exact_tokens(
+        &ctx.accounts.global_config,
+        token_amount,
+        referrer.is_some(),
+        sol_usd_price.as_ref(),
+    )?;
+
+    if quote.fees.gross > max_sol_cost {
+        return Err(error!(Errors::SlippageExceeded));
+    }
+
+    buy::execute_buy(ctx, quote, referrer, sol_usd_price)
+}
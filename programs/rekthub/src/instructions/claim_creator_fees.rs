@@ -0,0 +1,41 @@
+use super::*;
+
+pub fn claim_creator_fees_ix(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let amount = ctx
+        .accounts
+        .creator_fee_vault
+        .lamports()
+        .saturating_sub(rent_exempt_minimum);
+
+    require!(amount > 0, Errors::InsufficientFunds);
+
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        CREATOR_FEE_VAULT.as_bytes(),
+        mint_key.as_ref(),
+        &[ctx.bumps.creator_fee_vault],
+    ]];
+
+    utils::transfer_sol(
+        &ctx.accounts.creator_fee_vault.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+        Some(signer_seeds),
+    )?;
+
+    let event = CreatorFeesClaimed {
+        creator: ctx.accounts.creator.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
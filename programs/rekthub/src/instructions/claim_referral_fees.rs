@@ -0,0 +1,31 @@
+use super::*;
+
+pub fn claim_referral_fees_ix(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let referrer_stats = ctx.accounts.referrer_stats.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(referrer_stats.data_len());
+    let amount = referrer_stats
+        .lamports()
+        .saturating_sub(rent_exempt_minimum);
+
+    require!(amount > 0, Errors::InsufficientFunds);
+
+    utils::move_lamports(
+        &referrer_stats,
+        &ctx.accounts.referrer.to_account_info(),
+        amount,
+    )?;
+
+    let event = ReferralFeesClaimed {
+        referrer: ctx.accounts.referrer.key(),
+        referrer_stats: ctx.accounts.referrer_stats.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
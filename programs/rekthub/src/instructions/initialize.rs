@@ -5,9 +5,32 @@ pub fn initialize_ix(
     name: String,
     symbol: String,
     uri: String,
+    curve_params: Option<CurveParams>,
+    curve_model: Option<CurveModel>,
+    migration_target: Option<MigrationTarget>,
 ) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    let curve_params = curve_params.unwrap_or_else(|| global_config.curve_params());
+    let curve_model = curve_model.unwrap_or(CurveModel::ConstantProduct);
+    let migration_target = migration_target.unwrap_or(MigrationTarget::RaydiumCpmm);
+    curve_params.validate(
+        &curve_model,
+        &global_config.graduation_trigger,
+        global_config.migration_fee,
+        &migration_target,
+    )?;
+
+    if migration_target == MigrationTarget::MeteoraDamm {
+        require!(
+            global_config.meteora_pool_config != Pubkey::default(),
+            Errors::MigrationTargetUnavailable
+        );
+    }
+
     require!(
-        **ctx.accounts.creator.to_account_info().lamports.borrow() >= TOKEN_INITIALISATION_FEE,
+        **ctx.accounts.creator.to_account_info().lamports.borrow()
+            >= global_config.token_initialisation_fee,
         Errors::InsufficientFunds
     );
 
@@ -51,13 +74,22 @@ pub fn initialize_ix(
         signer_seeds,
     );
 
-    mint_to(mint_token_cpi_ctx, TOTAL_TOKEN_SUPPLY)?;
+    mint_to(mint_token_cpi_ctx, curve_params.total_token_supply)?;
 
     utils::transfer_sol(
         &ctx.accounts.creator.to_account_info(),
         &ctx.accounts.global_fee_vault.to_account_info(),
         &ctx.accounts.system_program.to_account_info(),
-        TOKEN_INITIALISATION_FEE,
+        global_config.token_initialisation_fee,
+        None,
+    )?;
+
+    // Keeps the vault rent-exempt so it can accrue fee shares of any size
+    utils::transfer_sol(
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.creator_fee_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Rent::get()?.minimum_balance(0),
         None,
     )?;
 
@@ -66,14 +98,24 @@ pub fn initialize_ix(
     ctx.accounts.bonding_curve.authority = ctx.accounts.mint_authority.key();
 
     ctx.accounts.bonding_curve.real_sol_reserves = REAL_SOL_RESERVES;
-    ctx.accounts.bonding_curve.real_token_reserves = REAL_TOKEN_RESERVES;
+    ctx.accounts.bonding_curve.real_token_reserves = curve_params.real_token_reserves;
+
+    ctx.accounts.bonding_curve.virtual_sol_reserves = curve_params.virtual_sol_reserves;
+    ctx.accounts.bonding_curve.virtual_token_reserves = curve_params.virtual_token_reserves;
 
-    ctx.accounts.bonding_curve.virtual_sol_reserves = VIRTUAL_SOL_RESERVES;
-    ctx.accounts.bonding_curve.virtual_token_reserves = VIRTUAL_TOKEN_RESERVES;
+    ctx.accounts.bonding_curve.total_token_supply = curve_params.total_token_supply;
+    ctx.accounts.bonding_curve.initial_real_token_reserves = curve_params.real_token_reserves;
+    ctx.accounts.bonding_curve.curve_model = curve_model;
+    ctx.accounts.bonding_curve.graduation_trigger = global_config.graduation_trigger;
+    ctx.accounts.bonding_curve.lp_disposition = global_config.lp_disposition;
+    ctx.accounts.bonding_curve.migration_target = migration_target;
+    ctx.accounts.bonding_curve.migration_fee = global_config.migration_fee;
+    ctx.accounts.bonding_curve.migration_reward = global_config.migration_reward;
+    ctx.accounts.bonding_curve.status = CurveStatus::Trading;
 
-    ctx.accounts.bonding_curve.total_token_supply = TOTAL_TOKEN_SUPPLY;
-    ctx.accounts.bonding_curve.is_bonding_curve_complete = false;
-    ctx.accounts.bonding_curve.has_curve_migrated = false;
+    let clock = Clock::get()?;
+    ctx.accounts.bonding_curve.created_slot = clock.slot;
+    ctx.accounts.bonding_curve.created_at = clock.unix_timestamp;
 
     let event = OnInitializeEvent {
         creator: ctx.accounts.creator.key(),
@@ -83,11 +125,17 @@ pub fn initialize_ix(
         name,
         symbol,
         uri,
-        virtual_sol_reserves: VIRTUAL_SOL_RESERVES,
-        virtual_token_reserves: VIRTUAL_TOKEN_RESERVES,
-        real_sol_reserves: REAL_SOL_RESERVES,
-        real_token_reserves: REAL_TOKEN_RESERVES,
-        timestamp: Clock::get()?.unix_timestamp,
+        virtual_sol_reserves: ctx.accounts.bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: ctx.accounts.bonding_curve.virtual_token_reserves,
+        real_sol_reserves: ctx.accounts.bonding_curve.real_sol_reserves,
+        real_token_reserves: ctx.accounts.bonding_curve.real_token_reserves,
+        total_token_supply: ctx.accounts.bonding_curve.total_token_supply,
+        curve_model,
+        graduation_trigger: global_config.graduation_trigger,
+        lp_disposition: global_config.lp_disposition,
+        migration_target,
+        created_slot: clock.slot,
+        timestamp: clock.unix_timestamp,
     };
 
     emit_cpi!(event);
@@ -0,0 +1,17 @@
+use super::*;
+
+pub fn initialize_config_ix(ctx: Context<InitializeConfig>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    global_config.set_defaults(ctx.accounts.authority.key());
+    global_config.validate()?;
+
+    let event = global_config.updated_event(Clock::get()?.unix_timestamp);
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
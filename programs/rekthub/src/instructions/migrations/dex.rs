@@ -0,0 +1,41 @@
+use super::*;
+
+/// Tokens a DEX backend seeds a new pool with. Both token accounts belong to the migration
+/// authority, which signs with `signer_seeds` and pays for the pool's accounts.
+pub struct PoolDeposit<'a, 'info> {
+    pub migration_authority: &'a AccountInfo<'info>,
+    pub signer_seeds: &'a [&'a [&'a [u8]]],
+    /// Wrapped SOL
+    pub mint_0: &'a AccountInfo<'info>,
+    pub mint_1: &'a AccountInfo<'info>,
+    pub token_0: &'a AccountInfo<'info>,
+    pub token_1: &'a AccountInfo<'info>,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub token_program: &'a AccountInfo<'info>,
+    pub mint_0_program: &'a AccountInfo<'info>,
+    pub mint_1_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+}
+
+/// A DEX completed curves can migrate into.
+pub trait DexAdapter<'info> {
+    /// Target a curve must have chosen at `initialize` to migrate through this DEX.
+    const TARGET: MigrationTarget;
+
+    /// Lamports the migration authority keeps back from the pool's SOL to pay for the pool's
+    /// accounts and any create fee. Whatever is left over goes to the fee vault.
+    const POOL_CREATION_RESERVE: u64;
+
+    /// Creates the pool from `deposit`, minting its LP tokens to `lp_token`.
+    fn create_pool(&self, deposit: &PoolDeposit<'_, 'info>) -> Result<()>;
+
+    fn pool_state(&self) -> Pubkey;
+
+    fn lp_mint(&self) -> &AccountInfo<'info>;
+
+    /// Migration authority's LP token account.
+    fn lp_token(&self) -> &AccountInfo<'info>;
+}
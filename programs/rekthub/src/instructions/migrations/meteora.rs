@@ -0,0 +1,236 @@
+use super::*;
+
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use constants::meteora::*;
+
+/// Migrates a completed curve to a Meteora DAMM constant-product pool in one transaction, see
+/// [`migrate_curve`].
+pub fn migrate_meteora_ix(ctx: Context<MigrateMeteora>) -> Result<()> {
+    let meteora = MeteoraDamm::from(&*ctx.accounts);
+    let outcome = migrate_curve(&mut ctx.accounts.curve, &ctx.bumps.curve, &meteora)?;
+
+    let event = ctx
+        .accounts
+        .curve
+        .migrated_event(&meteora, &outcome, Clock::get()?.unix_timestamp);
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
+
+/// Meteora DAMM pool accounts, created under `GlobalConfig.meteora_pool_config`. The curve's
+/// token is the pool's token A and wrapped SOL its token B.
+pub struct MeteoraDamm<'info> {
+    pub pool_config: AccountInfo<'info>,
+    pub pool: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub payer_pool_lp: AccountInfo<'info>,
+    pub a_vault: AccountInfo<'info>,
+    pub b_vault: AccountInfo<'info>,
+    pub a_token_vault: AccountInfo<'info>,
+    pub b_token_vault: AccountInfo<'info>,
+    pub a_vault_lp_mint: AccountInfo<'info>,
+    pub b_vault_lp_mint: AccountInfo<'info>,
+    pub a_vault_lp: AccountInfo<'info>,
+    pub b_vault_lp: AccountInfo<'info>,
+    pub protocol_token_a_fee: AccountInfo<'info>,
+    pub protocol_token_b_fee: AccountInfo<'info>,
+    pub mint_metadata: AccountInfo<'info>,
+    pub metadata_program: AccountInfo<'info>,
+    pub vault_program: AccountInfo<'info>,
+    pub damm_program: AccountInfo<'info>,
+}
+
+impl<'info> From<&MigrateMeteora<'info>> for MeteoraDamm<'info> {
+    fn from(accounts: &MigrateMeteora<'info>) -> Self {
+        Self {
+            pool_config: accounts.pool_config.to_account_info(),
+            pool: accounts.pool.to_account_info(),
+            lp_mint: accounts.lp_mint.to_account_info(),
+            payer_pool_lp: accounts.payer_pool_lp.to_account_info(),
+            a_vault: accounts.a_vault.to_account_info(),
+            b_vault: accounts.b_vault.to_account_info(),
+            a_token_vault: accounts.a_token_vault.to_account_info(),
+            b_token_vault: accounts.b_token_vault.to_account_info(),
+            a_vault_lp_mint: accounts.a_vault_lp_mint.to_account_info(),
+            b_vault_lp_mint: accounts.b_vault_lp_mint.to_account_info(),
+            a_vault_lp: accounts.a_vault_lp.to_account_info(),
+            b_vault_lp: accounts.b_vault_lp.to_account_info(),
+            protocol_token_a_fee: accounts.protocol_token_a_fee.to_account_info(),
+            protocol_token_b_fee: accounts.protocol_token_b_fee.to_account_info(),
+            mint_metadata: accounts.mint_metadata.to_account_info(),
+            metadata_program: accounts.metadata_program.to_account_info(),
+            vault_program: accounts.vault_program.to_account_info(),
+            damm_program: accounts.damm_program.to_account_info(),
+        }
+    }
+}
+
+impl<'info> MeteoraDamm<'info> {
+    /// Creates `mint`'s Meteora vault unless it already exists. The pool deposits both tokens
+    /// through their vaults, and a freshly launched token has none. The migration authority
+    /// pays for the vault out of the pool creation reserve.
+    fn initialize_vault(
+        &self,
+        deposit: &PoolDeposit<'_, 'info>,
+        vault: &AccountInfo<'info>,
+        token_vault: &AccountInfo<'info>,
+        mint: &AccountInfo<'info>,
+        lp_mint: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if !vault.data_is_empty() {
+            return Ok(());
+        }
+
+        let account_infos = [
+            vault.clone(),
+            deposit.migration_authority.clone(),
+            token_vault.clone(),
+            mint.clone(),
+            lp_mint.clone(),
+            deposit.rent.clone(),
+            deposit.token_program.clone(),
+            deposit.system_program.clone(),
+            self.vault_program.clone(),
+        ];
+
+        let accounts = vec![
+            AccountMeta::new(vault.key(), false),
+            AccountMeta::new(deposit.migration_authority.key(), true),
+            AccountMeta::new(token_vault.key(), false),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new(lp_mint.key(), false),
+            AccountMeta::new_readonly(deposit.rent.key(), false),
+            AccountMeta::new_readonly(deposit.token_program.key(), false),
+            AccountMeta::new_readonly(deposit.system_program.key(), false),
+        ];
+
+        invoke_signed(
+            &Instruction {
+                program_id: VAULT_PROGRAM_ID,
+                accounts,
+                data: INITIALIZE_VAULT_DISCRIMINATOR.to_vec(),
+            },
+            &account_infos,
+            deposit.signer_seeds,
+        )
+        .map_err(Into::into)
+    }
+}
+
+impl<'info> DexAdapter<'info> for MeteoraDamm<'info> {
+    const TARGET: MigrationTarget = MigrationTarget::MeteoraDamm;
+
+    const POOL_CREATION_RESERVE: u64 = constants::meteora::POOL_CREATION_RESERVE;
+
+    /// Meteora ships no CPI crate, so `initialize_permissionless_constant_product_pool_with_config`
+    /// is built by hand.
+    fn create_pool(&self, deposit: &PoolDeposit<'_, 'info>) -> Result<()> {
+        self.initialize_vault(
+            deposit,
+            &self.a_vault,
+            &self.a_token_vault,
+            deposit.mint_1,
+            &self.a_vault_lp_mint,
+        )?;
+        self.initialize_vault(
+            deposit,
+            &self.b_vault,
+            &self.b_token_vault,
+            deposit.mint_0,
+            &self.b_vault_lp_mint,
+        )?;
+
+        let account_infos = [
+            self.pool.clone(),
+            self.pool_config.clone(),
+            self.lp_mint.clone(),
+            deposit.mint_1.clone(),
+            deposit.mint_0.clone(),
+            self.a_vault.clone(),
+            self.b_vault.clone(),
+            self.a_token_vault.clone(),
+            self.b_token_vault.clone(),
+            self.a_vault_lp_mint.clone(),
+            self.b_vault_lp_mint.clone(),
+            self.a_vault_lp.clone(),
+            self.b_vault_lp.clone(),
+            deposit.token_1.clone(),
+            deposit.token_0.clone(),
+            self.payer_pool_lp.clone(),
+            self.protocol_token_a_fee.clone(),
+            self.protocol_token_b_fee.clone(),
+            deposit.migration_authority.clone(),
+            self.mint_metadata.clone(),
+            deposit.rent.clone(),
+            self.metadata_program.clone(),
+            self.vault_program.clone(),
+            deposit.token_program.clone(),
+            deposit.associated_token_program.clone(),
+            deposit.system_program.clone(),
+        ];
+
+        let accounts = vec![
+            AccountMeta::new(self.pool.key(), false),
+            AccountMeta::new_readonly(self.pool_config.key(), false),
+            AccountMeta::new(self.lp_mint.key(), false),
+            AccountMeta::new_readonly(deposit.mint_1.key(), false),
+            AccountMeta::new_readonly(deposit.mint_0.key(), false),
+            AccountMeta::new(self.a_vault.key(), false),
+            AccountMeta::new(self.b_vault.key(), false),
+            AccountMeta::new(self.a_token_vault.key(), false),
+            AccountMeta::new(self.b_token_vault.key(), false),
+            AccountMeta::new(self.a_vault_lp_mint.key(), false),
+            AccountMeta::new(self.b_vault_lp_mint.key(), false),
+            AccountMeta::new(self.a_vault_lp.key(), false),
+            AccountMeta::new(self.b_vault_lp.key(), false),
+            AccountMeta::new(deposit.token_1.key(), false),
+            AccountMeta::new(deposit.token_0.key(), false),
+            AccountMeta::new(self.payer_pool_lp.key(), false),
+            AccountMeta::new(self.protocol_token_a_fee.key(), false),
+            AccountMeta::new(self.protocol_token_b_fee.key(), false),
+            AccountMeta::new(deposit.migration_authority.key(), true),
+            AccountMeta::new(self.mint_metadata.key(), false),
+            AccountMeta::new_readonly(deposit.rent.key(), false),
+            AccountMeta::new_readonly(self.metadata_program.key(), false),
+            AccountMeta::new_readonly(self.vault_program.key(), false),
+            AccountMeta::new_readonly(deposit.token_program.key(), false),
+            AccountMeta::new_readonly(deposit.associated_token_program.key(), false),
+            AccountMeta::new_readonly(deposit.system_program.key(), false),
+        ];
+
+        let mut data = INITIALIZE_POOL_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&deposit.token_amount.to_le_bytes());
+        data.extend_from_slice(&deposit.sol_amount.to_le_bytes());
+
+        invoke_signed(
+            &Instruction {
+                program_id: DAMM_PROGRAM_ID,
+                accounts,
+                data,
+            },
+            &account_infos,
+            deposit.signer_seeds,
+        )
+        .map_err(Into::into)
+    }
+
+    fn pool_state(&self) -> Pubkey {
+        self.pool.key()
+    }
+
+    fn lp_mint(&self) -> &AccountInfo<'info> {
+        &self.lp_mint
+    }
+
+    fn lp_token(&self) -> &AccountInfo<'info> {
+        &self.payer_pool_lp
+    }
+}
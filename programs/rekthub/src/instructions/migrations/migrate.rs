@@ -0,0 +1,214 @@
+use super::*;
+
+/// What a curve migration moved, for the instruction's events.
+pub struct MigrationOutcome {
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub migration_fee: u64,
+    pub cranker_reward: u64,
+    pub lp_tokens_minted: u64,
+}
+
+/// Migrates a completed curve to a Raydium CPMM pool in one transaction, see [`migrate_curve`].
+pub fn migrate_ix(ctx: Context<Migrate>) -> Result<()> {
+    let raydium = RaydiumCpmm::from(&*ctx.accounts);
+    let outcome = migrate_curve(&mut ctx.accounts.curve, &ctx.bumps.curve, &raydium)?;
+
+    let curve = &ctx.accounts.curve;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let event = RaydiumPoolInitialized {
+        mint_0: curve.mint_0.key(),
+        mint_1: curve.mint_1.key(),
+        pool_state: raydium.pool_state(),
+        lp_mint: raydium.lp_mint.key(),
+        migration_authority: curve.migration_authority.key(),
+        bonding_curve: curve.bonding_curve.key(),
+        initial_token_0_amount: outcome.sol_amount,
+        initial_token_1_amount: outcome.token_amount,
+        lp_tokens_minted: outcome.lp_tokens_minted,
+        lp_disposition: curve.bonding_curve.lp_disposition,
+        timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    let event = curve.migrated_event(&raydium, &outcome, timestamp);
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
+
+/// Moves a completed curve's tokens and SOL into a new `dex` pool, takes the migration fee, pays
+/// the cranker and burns or locks the LP tokens.
+///
+/// The migration instructions built on this are permissionless: anyone may call them, and the
+/// caller earns `migration_reward` out of the migration fee.
+pub fn migrate_curve<'info, D: DexAdapter<'info>>(
+    curve: &mut MigrateCurve<'info>,
+    bumps: &MigrateCurveBumps,
+    dex: &D,
+) -> Result<MigrationOutcome> {
+    require!(
+        curve.bonding_curve.migration_target == D::TARGET,
+        Errors::WrongMigrationTarget
+    );
+    curve
+        .bonding_curve
+        .transition_to(CurveStatus::MigrationPrepared)?;
+    curve.bonding_curve.transition_to(CurveStatus::Migrated)?;
+
+    let migration_fee = curve.bonding_curve.migration_fee;
+    let cranker_reward = curve.bonding_curve.migration_reward;
+    let protocol_fee = migration_fee
+        .checked_sub(cranker_reward)
+        .ok_or(Errors::MathOverflow)?;
+    let sol_amount = curve
+        .bonding_curve
+        .real_sol_reserves
+        .checked_sub(migration_fee)
+        .and_then(|amount| amount.checked_sub(D::POOL_CREATION_RESERVE))
+        .ok_or(Errors::InsufficientFunds)?;
+    let token_amount = curve.associated_bonding_curve.amount;
+
+    let mint_key = curve.mint_1.key();
+    let bonding_curve_seeds: &[&[&[u8]]] = &[&[
+        BONDING_CURVE.as_bytes(),
+        mint_key.as_ref(),
+        &[bumps.bonding_curve],
+    ]];
+    let migration_authority_seeds: &[&[&[u8]]] = &[&[
+        MIGRATION_AUTHORITY.as_bytes(),
+        mint_key.as_ref(),
+        &[bumps.migration_authority],
+    ]];
+
+    utils::transfer_tokens(
+        &curve.associated_bonding_curve,
+        &curve.associated_migration_authority,
+        &curve.bonding_curve.to_account_info(),
+        &curve.mint_1,
+        &curve.mint_1_program,
+        token_amount,
+        Some(bonding_curve_seeds),
+    )?;
+
+    // Pool SOL goes straight into the migration authority's wrapped SOL account
+    let bonding_curve = curve.bonding_curve.to_account_info();
+    utils::move_lamports(
+        &bonding_curve,
+        &curve.associated_mint_0.to_account_info(),
+        sol_amount,
+    )?;
+    utils::move_lamports(
+        &bonding_curve,
+        &curve.migration_authority.to_account_info(),
+        D::POOL_CREATION_RESERVE,
+    )?;
+    utils::move_lamports(
+        &bonding_curve,
+        &curve.global_fee_vault.to_account_info(),
+        protocol_fee,
+    )?;
+    utils::move_lamports(
+        &bonding_curve,
+        &curve.signer.to_account_info(),
+        cranker_reward,
+    )?;
+    curve.bonding_curve.real_sol_reserves = 0;
+    curve.bonding_curve.real_token_reserves = 0;
+
+    token::sync_native(CpiContext::new(
+        curve.token_program.to_account_info(),
+        token::SyncNative {
+            account: curve.associated_mint_0.to_account_info(),
+        },
+    ))?;
+
+    dex.create_pool(&PoolDeposit {
+        migration_authority: &curve.migration_authority,
+        signer_seeds: migration_authority_seeds,
+        mint_0: &curve.mint_0.to_account_info(),
+        mint_1: &curve.mint_1.to_account_info(),
+        token_0: &curve.associated_mint_0.to_account_info(),
+        token_1: &curve.associated_migration_authority.to_account_info(),
+        sol_amount,
+        token_amount,
+        token_program: &curve.token_program.to_account_info(),
+        mint_0_program: &curve.mint_0_program.to_account_info(),
+        mint_1_program: &curve.mint_1_program.to_account_info(),
+        associated_token_program: &curve.associated_token_program.to_account_info(),
+        system_program: &curve.system_program.to_account_info(),
+        rent: &curve.rent.to_account_info(),
+    })?;
+
+    let lp_tokens_minted = utils::dispose_lp_tokens(
+        utils::LpDisposal {
+            mint: curve.mint_1.key(),
+            pool_state: dex.pool_state(),
+            beneficiary: curve.bonding_curve.creator,
+            lp_mint: dex.lp_mint(),
+            lp_token: dex.lp_token(),
+            owner: &curve.migration_authority.to_account_info(),
+            owner_seeds: migration_authority_seeds,
+            lp_lock: curve.lp_lock.as_deref_mut(),
+            lp_lock_token: curve
+                .lp_lock_token
+                .as_ref()
+                .map(|lp_lock_token| lp_lock_token.as_ref()),
+            payer: &curve.signer.to_account_info(),
+            token_program: &curve.token_program.to_account_info(),
+            associated_token_program: &curve.associated_token_program.to_account_info(),
+            system_program: &curve.system_program.to_account_info(),
+        },
+        curve.bonding_curve.lp_disposition,
+    )?;
+
+    // Whatever the pool accounts did not use goes back to the protocol
+    utils::transfer_sol(
+        &curve.migration_authority,
+        &curve.global_fee_vault,
+        &curve.system_program,
+        curve.migration_authority.lamports(),
+        Some(migration_authority_seeds),
+    )?;
+
+    Ok(MigrationOutcome {
+        sol_amount,
+        token_amount,
+        migration_fee,
+        cranker_reward,
+        lp_tokens_minted,
+    })
+}
+
+impl MigrateCurve<'_> {
+    pub fn migrated_event<'info, D: DexAdapter<'info>>(
+        &self,
+        dex: &D,
+        outcome: &MigrationOutcome,
+        timestamp: i64,
+    ) -> CurveMigrated {
+        CurveMigrated {
+            mint: self.mint_1.key(),
+            bonding_curve: self.bonding_curve.key(),
+            migration_target: D::TARGET,
+            pool_state: dex.pool_state(),
+            lp_mint: dex.lp_mint().key(),
+            cranker: self.signer.key(),
+            sol_amount: outcome.sol_amount,
+            token_amount: outcome.token_amount,
+            migration_fee: outcome.migration_fee,
+            cranker_reward: outcome.cranker_reward,
+            lp_tokens_minted: outcome.lp_tokens_minted,
+            timestamp,
+        }
+    }
+}
@@ -1,6 +1,19 @@
 use super::*;
 
+pub mod dex;
+pub use dex::*;
+
 pub mod raydium;
+pub use raydium::RaydiumCpmm;
+
+pub mod meteora;
+pub use meteora::{migrate_meteora_ix, MeteoraDamm};
+
+pub mod pool;
+pub use pool::{migrate_to_pool_ix, RekthubPool};
+
+pub mod migrate;
+pub use migrate::{migrate_curve, migrate_ix, MigrationOutcome};
 
 pub mod prepare_curve_migration;
 pub use prepare_curve_migration::prepare_curve_migration_ix;
\ No newline at end of file
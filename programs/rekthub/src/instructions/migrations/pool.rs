@@ -0,0 +1,128 @@
+use super::*;
+
+/// Migrates a completed curve into its own `Pool`, without any external CPI, see
+/// [`migrate_curve`].
+pub fn migrate_to_pool_ix(ctx: Context<MigrateToPool>) -> Result<()> {
+    let pool = RekthubPool::new(ctx.accounts, ctx.bumps.pool);
+    let outcome = migrate_curve(&mut ctx.accounts.curve, &ctx.bumps.curve, &pool)?;
+
+    let pool_state = PoolState::new(outcome.sol_amount, outcome.token_amount, POOL_FEE_BPS)
+        .map_err(Errors::from)?;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let pool_account = &mut ctx.accounts.pool;
+    pool_account.mint = ctx.accounts.curve.mint_1.key();
+    pool_account.lp_mint = ctx.accounts.lp_mint.key();
+    pool_account.set_pool_state(&pool_state);
+    pool_account.created_at = timestamp;
+
+    let event = ctx
+        .accounts
+        .curve
+        .migrated_event(&pool, &outcome, timestamp);
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
+
+/// RektHub's own constant-product `Pool`. It holds its SOL as lamports rather than wrapped SOL
+/// and mints its own LP tokens, with the pool PDA as the LP mint authority.
+pub struct RekthubPool<'info> {
+    pub pool: AccountInfo<'info>,
+    pub pool_bump: u8,
+    pub associated_pool: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub migration_authority_lp_token: AccountInfo<'info>,
+    pub mint_decimals: u8,
+}
+
+impl<'info> RekthubPool<'info> {
+    pub fn new(accounts: &MigrateToPool<'info>, pool_bump: u8) -> Self {
+        Self {
+            pool: accounts.pool.to_account_info(),
+            pool_bump,
+            associated_pool: accounts.associated_pool.to_account_info(),
+            lp_mint: accounts.lp_mint.to_account_info(),
+            migration_authority_lp_token: accounts.migration_authority_lp_token.to_account_info(),
+            mint_decimals: accounts.curve.mint_1.decimals,
+        }
+    }
+}
+
+impl<'info> DexAdapter<'info> for RekthubPool<'info> {
+    const TARGET: MigrationTarget = MigrationTarget::RekthubPool;
+
+    // The cranker pays for the pool's accounts when they are initialized
+    const POOL_CREATION_RESERVE: u64 = 0;
+
+    /// Unwraps the deposit's SOL into the pool, moves its tokens into the pool's token account
+    /// and mints all but `MINIMUM_LIQUIDITY` of the pool's LP tokens to `lp_token`.
+    fn create_pool(&self, deposit: &PoolDeposit<'_, 'info>) -> Result<()> {
+        let pool_state = PoolState::new(deposit.sol_amount, deposit.token_amount, POOL_FEE_BPS)
+            .map_err(Errors::from)?;
+
+        // Closing the wrapped SOL account hands its lamports back to the migration authority
+        token::close_account(CpiContext::new_with_signer(
+            deposit.token_program.clone(),
+            token::CloseAccount {
+                account: deposit.token_0.clone(),
+                destination: deposit.migration_authority.clone(),
+                authority: deposit.migration_authority.clone(),
+            },
+            deposit.signer_seeds,
+        ))?;
+        utils::transfer_sol(
+            deposit.migration_authority,
+            &self.pool,
+            deposit.system_program,
+            deposit.sol_amount,
+            Some(deposit.signer_seeds),
+        )?;
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                deposit.mint_1_program.clone(),
+                TransferChecked {
+                    from: deposit.token_1.clone(),
+                    to: self.associated_pool.clone(),
+                    authority: deposit.migration_authority.clone(),
+                    mint: deposit.mint_1.clone(),
+                },
+                deposit.signer_seeds,
+            ),
+            deposit.token_amount,
+            self.mint_decimals,
+        )?;
+
+        let mint_key = deposit.mint_1.key();
+        let pool_seeds: &[&[&[u8]]] = &[&[POOL.as_bytes(), mint_key.as_ref(), &[self.pool_bump]]];
+        mint_to(
+            CpiContext::new_with_signer(
+                deposit.token_program.clone(),
+                MintTo {
+                    mint: self.lp_mint.clone(),
+                    to: self.migration_authority_lp_token.clone(),
+                    authority: self.pool.clone(),
+                },
+                pool_seeds,
+            ),
+            pool_state.initial_lp_tokens().map_err(Errors::from)?,
+        )
+    }
+
+    fn pool_state(&self) -> Pubkey {
+        self.pool.key()
+    }
+
+    fn lp_mint(&self) -> &AccountInfo<'info> {
+        &self.lp_mint
+    }
+
+    fn lp_token(&self) -> &AccountInfo<'info> {
+        &self.migration_authority_lp_token
+    }
+}
@@ -1,10 +1,14 @@
 use super::*;
 
 pub fn prepare_curve_migration_ix(ctx: Context<PrepareCurveMigration>) -> Result<()> {
+    // Only Raydium has a second, `initialize_raydium_pool` step to finish the migration
     require!(
-        ctx.accounts.bonding_curve.is_bonding_curve_complete,
-        Errors::BondingCurveNotComplete
+        ctx.accounts.bonding_curve.migration_target == RaydiumCpmm::TARGET,
+        Errors::WrongMigrationTarget
     );
+    ctx.accounts
+        .bonding_curve
+        .transition_to(CurveStatus::MigrationPrepared)?;
 
     let mint_key = ctx.accounts.mint.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -12,11 +16,11 @@ pub fn prepare_curve_migration_ix(ctx: Context<PrepareCurveMigration>) -> Result
         mint_key.as_ref(),
         &[ctx.bumps.bonding_curve],
     ]];
-    let net_amount = ctx
-        .accounts
-        .bonding_curve
-        .real_sol_reserves
-        .checked_sub(MIGRATION_FEE)
+    let migration_fee = ctx.accounts.bonding_curve.migration_fee;
+    let sol_amount = ctx.accounts.bonding_curve.real_sol_reserves;
+    let token_amount = ctx.accounts.bonding_curve.real_token_reserves;
+    let net_amount = sol_amount
+        .checked_sub(migration_fee)
         .ok_or(Errors::MathOverflow)?;
 
     utils::transfer_tokens(
@@ -29,30 +33,27 @@ pub fn prepare_curve_migration_ix(ctx: Context<PrepareCurveMigration>) -> Result
         Some(&signer_seeds),
     )?;
 
-    **ctx
-        .accounts
-        .bonding_curve
-        .to_account_info()
-        .try_borrow_mut_lamports()? -= ctx.accounts.bonding_curve.real_sol_reserves;
+    utils::move_lamports(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        &ctx.accounts.migration_authority.to_account_info(),
+        net_amount,
+    )?;
 
-    **ctx
-        .accounts
-        .migration_authority
-        .to_account_info()
-        .try_borrow_mut_lamports()? += net_amount;
+    utils::move_lamports(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        &ctx.accounts.global_fee_vault.to_account_info(),
+        migration_fee,
+    )?;
 
-    **ctx
-        .accounts
-        .global_fee_vault
-        .to_account_info()
-        .try_borrow_mut_lamports()? += MIGRATION_FEE;
+    ctx.accounts.bonding_curve.real_sol_reserves = 0;
+    ctx.accounts.bonding_curve.real_token_reserves = 0;
 
     let event = CurveMigrationPrepared {
         mint: ctx.accounts.mint.key(),
         bonding_curve: ctx.accounts.bonding_curve.key(),
         migration_authority: ctx.accounts.migration_authority.key(),
-        token_amount: ctx.accounts.bonding_curve.real_token_reserves,
-        sol_amount: ctx.accounts.bonding_curve.real_sol_reserves,
+        token_amount,
+        sol_amount,
         timestamp: Clock::get()?.unix_timestamp,
     };
 
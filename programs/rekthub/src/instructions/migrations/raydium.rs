@@ -4,14 +4,12 @@ pub use constants::raydium::*;
 
 pub fn initialize_pool(ctx: Context<InitializeRaydiumPool>) -> Result<()> {
     require!(
-        ctx.accounts.bonding_curve.is_bonding_curve_complete,
-        Errors::BondingCurveNotComplete
-    );
-
-    require!(
-        !ctx.accounts.bonding_curve.has_curve_migrated,
-        Errors::BondingCurveMigrated
+        ctx.accounts.bonding_curve.migration_target == RaydiumCpmm::TARGET,
+        Errors::WrongMigrationTarget
     );
+    ctx.accounts
+        .bonding_curve
+        .transition_to(CurveStatus::Migrated)?;
 
     let mint_key = ctx.accounts.mint_1.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -24,7 +22,7 @@ pub fn initialize_pool(ctx: Context<InitializeRaydiumPool>) -> Result<()> {
         .accounts
         .migration_authority
         .lamports()
-        .checked_sub(200_000_000)
+        .checked_sub(RaydiumCpmm::POOL_CREATION_RESERVE)
         .ok_or(Errors::InsufficientFunds)?;
 
     let token_amount = ctx.accounts.associated_migration_authority.amount;
@@ -44,39 +42,45 @@ pub fn initialize_pool(ctx: Context<InitializeRaydiumPool>) -> Result<()> {
         },
     ))?;
 
-    raydium_cp_swap::cpi::initialize(
-        CpiContext::new_with_signer(
-            ctx.accounts.raydium_program.to_account_info(),
-            raydium_cp_swap::cpi::accounts::Initialize {
-                creator: ctx.accounts.migration_authority.to_account_info(),
-                amm_config: ctx.accounts.amm_config.to_account_info(),
-                authority: ctx.accounts.authority.to_account_info(),
-                pool_state: ctx.accounts.pool_state.to_account_info(),
-                token_0_mint: ctx.accounts.mint_0.to_account_info(),
-                token_1_mint: ctx.accounts.mint_1.to_account_info(),
-                lp_mint: ctx.accounts.lp_mint.to_account_info(),
-                creator_token_0: ctx.accounts.associated_mint_0.to_account_info(),
-                creator_token_1: ctx
-                    .accounts
-                    .associated_migration_authority
-                    .to_account_info(),
-                creator_lp_token: ctx.accounts.creator_lp_token.to_account_info(),
-                token_0_vault: ctx.accounts.mint_0_vault.to_account_info(),
-                token_1_vault: ctx.accounts.mint_1_vault.to_account_info(),
-                create_pool_fee: ctx.accounts.create_pool_fee.to_account_info(),
-                observation_state: ctx.accounts.observation_state.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                token_0_program: ctx.accounts.mint_0_program.to_account_info(),
-                token_1_program: ctx.accounts.mint_1_program.to_account_info(),
-                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-            },
-            &signer_seeds,
-        ),
-        wrap_amount,
+    let raydium = RaydiumCpmm::from(&*ctx.accounts);
+    raydium.create_pool(&PoolDeposit {
+        migration_authority: &ctx.accounts.migration_authority,
+        signer_seeds,
+        mint_0: &ctx.accounts.mint_0.to_account_info(),
+        mint_1: &ctx.accounts.mint_1.to_account_info(),
+        token_0: &ctx.accounts.associated_mint_0.to_account_info(),
+        token_1: &ctx.accounts.associated_migration_authority.to_account_info(),
+        sol_amount: wrap_amount,
         token_amount,
-        Clock::get()?.unix_timestamp as u64,
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        mint_0_program: &ctx.accounts.mint_0_program.to_account_info(),
+        mint_1_program: &ctx.accounts.mint_1_program.to_account_info(),
+        associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        rent: &ctx.accounts.rent.to_account_info(),
+    })?;
+
+    let lp_tokens_minted = utils::dispose_lp_tokens(
+        utils::LpDisposal {
+            mint: ctx.accounts.mint_1.key(),
+            pool_state: ctx.accounts.pool_state.key(),
+            beneficiary: ctx.accounts.bonding_curve.creator,
+            lp_mint: &ctx.accounts.lp_mint.to_account_info(),
+            lp_token: &ctx.accounts.creator_lp_token.to_account_info(),
+            owner: &ctx.accounts.migration_authority.to_account_info(),
+            owner_seeds: signer_seeds,
+            lp_lock: ctx.accounts.lp_lock.as_deref_mut(),
+            lp_lock_token: ctx
+                .accounts
+                .lp_lock_token
+                .as_ref()
+                .map(|lp_lock_token| lp_lock_token.as_ref()),
+            payer: &ctx.accounts.signer.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        ctx.accounts.bonding_curve.lp_disposition,
     )?;
 
     utils::transfer_sol(
@@ -87,8 +91,6 @@ pub fn initialize_pool(ctx: Context<InitializeRaydiumPool>) -> Result<()> {
         Some(signer_seeds),
     )?;
 
-    ctx.accounts.bonding_curve.has_curve_migrated = true;
-
     let event = RaydiumPoolInitialized {
         mint_0: ctx.accounts.mint_0.key(),
         mint_1: ctx.accounts.mint_1.key(),
@@ -98,7 +100,8 @@ pub fn initialize_pool(ctx: Context<InitializeRaydiumPool>) -> Result<()> {
         bonding_curve: ctx.accounts.bonding_curve.key(),
         initial_token_0_amount: wrap_amount,
         initial_token_1_amount: token_amount,
-        lp_tokens_minted: 0,
+        lp_tokens_minted,
+        lp_disposition: ctx.accounts.bonding_curve.lp_disposition,
         timestamp: Clock::get()?.unix_timestamp,
     };
 
@@ -109,3 +112,103 @@ pub fn initialize_pool(ctx: Context<InitializeRaydiumPool>) -> Result<()> {
 
     Ok(())
 }
+
+/// Raydium CPMM pool accounts, created under the `POOL_INDEX` AMM config.
+pub struct RaydiumCpmm<'info> {
+    pub amm_config: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub pool_state: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub creator_lp_token: AccountInfo<'info>,
+    pub mint_0_vault: AccountInfo<'info>,
+    pub mint_1_vault: AccountInfo<'info>,
+    pub create_pool_fee: AccountInfo<'info>,
+    pub observation_state: AccountInfo<'info>,
+    pub raydium_program: AccountInfo<'info>,
+}
+
+impl<'info> From<&InitializeRaydiumPool<'info>> for RaydiumCpmm<'info> {
+    fn from(accounts: &InitializeRaydiumPool<'info>) -> Self {
+        Self {
+            amm_config: accounts.amm_config.to_account_info(),
+            authority: accounts.authority.to_account_info(),
+            pool_state: accounts.pool_state.to_account_info(),
+            lp_mint: accounts.lp_mint.to_account_info(),
+            creator_lp_token: accounts.creator_lp_token.to_account_info(),
+            mint_0_vault: accounts.mint_0_vault.to_account_info(),
+            mint_1_vault: accounts.mint_1_vault.to_account_info(),
+            create_pool_fee: accounts.create_pool_fee.to_account_info(),
+            observation_state: accounts.observation_state.to_account_info(),
+            raydium_program: accounts.raydium_program.to_account_info(),
+        }
+    }
+}
+
+impl<'info> From<&Migrate<'info>> for RaydiumCpmm<'info> {
+    fn from(accounts: &Migrate<'info>) -> Self {
+        Self {
+            amm_config: accounts.amm_config.to_account_info(),
+            authority: accounts.authority.to_account_info(),
+            pool_state: accounts.pool_state.to_account_info(),
+            lp_mint: accounts.lp_mint.to_account_info(),
+            creator_lp_token: accounts.creator_lp_token.to_account_info(),
+            mint_0_vault: accounts.mint_0_vault.to_account_info(),
+            mint_1_vault: accounts.mint_1_vault.to_account_info(),
+            create_pool_fee: accounts.create_pool_fee.to_account_info(),
+            observation_state: accounts.observation_state.to_account_info(),
+            raydium_program: accounts.raydium_program.to_account_info(),
+        }
+    }
+}
+
+impl<'info> DexAdapter<'info> for RaydiumCpmm<'info> {
+    const TARGET: MigrationTarget = MigrationTarget::RaydiumCpmm;
+
+    const POOL_CREATION_RESERVE: u64 = constants::raydium::POOL_CREATION_RESERVE;
+
+    fn create_pool(&self, deposit: &PoolDeposit<'_, 'info>) -> Result<()> {
+        raydium_cp_swap::cpi::initialize(
+            CpiContext::new_with_signer(
+                self.raydium_program.clone(),
+                raydium_cp_swap::cpi::accounts::Initialize {
+                    creator: deposit.migration_authority.clone(),
+                    amm_config: self.amm_config.clone(),
+                    authority: self.authority.clone(),
+                    pool_state: self.pool_state.clone(),
+                    token_0_mint: deposit.mint_0.clone(),
+                    token_1_mint: deposit.mint_1.clone(),
+                    lp_mint: self.lp_mint.clone(),
+                    creator_token_0: deposit.token_0.clone(),
+                    creator_token_1: deposit.token_1.clone(),
+                    creator_lp_token: self.creator_lp_token.clone(),
+                    token_0_vault: self.mint_0_vault.clone(),
+                    token_1_vault: self.mint_1_vault.clone(),
+                    create_pool_fee: self.create_pool_fee.clone(),
+                    observation_state: self.observation_state.clone(),
+                    token_program: deposit.token_program.clone(),
+                    token_0_program: deposit.mint_0_program.clone(),
+                    token_1_program: deposit.mint_1_program.clone(),
+                    associated_token_program: deposit.associated_token_program.clone(),
+                    system_program: deposit.system_program.clone(),
+                    rent: deposit.rent.clone(),
+                },
+                deposit.signer_seeds,
+            ),
+            deposit.sol_amount,
+            deposit.token_amount,
+            Clock::get()?.unix_timestamp as u64,
+        )
+    }
+
+    fn pool_state(&self) -> Pubkey {
+        self.pool_state.key()
+    }
+
+    fn lp_mint(&self) -> &AccountInfo<'info> {
+        &self.lp_mint
+    }
+
+    fn lp_token(&self) -> &AccountInfo<'info> {
+        &self.creator_lp_token
+    }
+}
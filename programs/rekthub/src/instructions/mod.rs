@@ -1,12 +1,45 @@
 use super::*;
 
+pub mod initialize_config;
+pub use initialize_config::initialize_config_ix;
+
+pub mod update_config;
+pub use update_config::update_config_ix;
+
+pub mod withdraw_fees;
+pub use withdraw_fees::withdraw_fees_ix;
+
 pub mod initialize;
 pub use initialize::initialize_ix;
 
 pub mod buy;
 pub use buy::buy_ix;
 
+pub mod buy_exact_tokens;
+pub use buy_exact_tokens::buy_exact_tokens_ix;
+
 pub mod sell;
 pub use sell::sell_ix;
 
+pub mod sell_for_exact_sol;
+pub use sell_for_exact_sol::sell_for_exact_sol_ix;
+
+pub mod quote;
+pub use quote::*;
+
+pub mod register_referrer;
+pub use register_referrer::register_referrer_ix;
+
+pub mod claim_referral_fees;
+pub use claim_referral_fees::claim_referral_fees_ix;
+
+pub mod claim_creator_fees;
+pub use claim_creator_fees::claim_creator_fees_ix;
+
+pub mod unlock_lp;
+pub use unlock_lp::unlock_lp_ix;
+
+pub mod pool;
+pub use pool::*;
+
 pub mod migrations;
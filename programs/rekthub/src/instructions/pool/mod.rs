@@ -0,0 +1,10 @@
+use super::*;
+
+pub mod swap;
+pub use swap::swap_ix;
+
+pub mod add_liquidity;
+pub use add_liquidity::add_liquidity_ix;
+
+pub mod remove_liquidity;
+pub use remove_liquidity::remove_liquidity_ix;
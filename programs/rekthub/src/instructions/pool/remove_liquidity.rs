@@ -0,0 +1,73 @@
+use super::*;
+
+pub fn remove_liquidity_ix(
+    ctx: Context<RemoveLiquidity>,
+    lp_amount: u64,
+    min_sol_amount: u64,
+    min_token_amount: u64,
+    deadline: Option<TradeDeadline>,
+) -> Result<()> {
+    utils::check_deadline(deadline)?;
+
+    let mut pool_state = ctx.accounts.pool.pool_state();
+    let quote = pool_state.quote_withdraw(lp_amount).map_err(Errors::from)?;
+
+    require!(
+        quote.sol_amount >= min_sol_amount && quote.token_amount >= min_token_amount,
+        Errors::SlippageExceeded
+    );
+
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[POOL.as_bytes(), mint_key.as_ref(), &[ctx.bumps.pool]]];
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.lp_token_program.to_account_info(),
+            token_interface::Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.provider_lp_token.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        ),
+        quote.lp_amount,
+    )?;
+
+    utils::transfer_tokens(
+        &ctx.accounts.associated_pool,
+        &ctx.accounts.associated_provider,
+        &ctx.accounts.pool.to_account_info(),
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        quote.token_amount,
+        Some(signer_seeds),
+    )?;
+
+    utils::move_lamports(
+        &ctx.accounts.pool.to_account_info(),
+        &ctx.accounts.provider.to_account_info(),
+        quote.sol_amount,
+    )?;
+
+    pool_state.apply_withdraw(&quote).map_err(Errors::from)?;
+    ctx.accounts.pool.set_pool_state(&pool_state);
+
+    let event = LiquidityRemoved {
+        mint: ctx.accounts.mint.key(),
+        pool: ctx.accounts.pool.key(),
+        provider: ctx.accounts.provider.key(),
+        sol_amount: quote.sol_amount,
+        token_amount: quote.token_amount,
+        lp_amount: quote.lp_amount,
+        sol_reserves: pool_state.sol_reserves,
+        token_reserves: pool_state.token_reserves,
+        lp_supply: pool_state.lp_supply,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
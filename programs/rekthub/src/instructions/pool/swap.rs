@@ -0,0 +1,84 @@
+use super::*;
+
+pub fn swap_ix(
+    ctx: Context<Swap>,
+    amount_in: u64,
+    min_amount_out: u64,
+    direction: SwapDirection,
+    deadline: Option<TradeDeadline>,
+) -> Result<()> {
+    utils::check_deadline(deadline)?;
+
+    let mut pool_state = ctx.accounts.pool.pool_state();
+    let quote = ctx.accounts.pool.quote_swap(amount_in, direction)?;
+
+    require!(quote.amount_out >= min_amount_out, Errors::SlippageExceeded);
+
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[POOL.as_bytes(), mint_key.as_ref(), &[ctx.bumps.pool]]];
+
+    match direction {
+        SwapDirection::Buy => {
+            utils::transfer_sol(
+                &ctx.accounts.user.to_account_info(),
+                &ctx.accounts.pool.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                quote.amount_in,
+                None,
+            )?;
+
+            utils::transfer_tokens(
+                &ctx.accounts.associated_pool,
+                &ctx.accounts.associated_user,
+                &ctx.accounts.pool.to_account_info(),
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                quote.amount_out,
+                Some(signer_seeds),
+            )?;
+
+            pool_state.apply_buy(&quote).map_err(Errors::from)?;
+        }
+        SwapDirection::Sell => {
+            utils::transfer_tokens(
+                &ctx.accounts.associated_user,
+                &ctx.accounts.associated_pool,
+                &ctx.accounts.user.to_account_info(),
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                quote.amount_in,
+                None,
+            )?;
+
+            utils::move_lamports(
+                &ctx.accounts.pool.to_account_info(),
+                &ctx.accounts.user.to_account_info(),
+                quote.amount_out,
+            )?;
+
+            pool_state.apply_sell(&quote).map_err(Errors::from)?;
+        }
+    }
+
+    ctx.accounts.pool.set_pool_state(&pool_state);
+
+    let event = PoolSwapped {
+        mint: ctx.accounts.mint.key(),
+        pool: ctx.accounts.pool.key(),
+        user: ctx.accounts.user.key(),
+        direction,
+        amount_in: quote.amount_in,
+        amount_out: quote.amount_out,
+        fee: quote.fee,
+        sol_reserves: pool_state.sol_reserves,
+        token_reserves: pool_state.token_reserves,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+pub fn quote_buy_ix(
+    ctx: Context<Quote>,
+    amount_in_sol: u64,
+    has_referrer: bool,
+) -> Result<TradeQuote> {
+    let sol_usd_price =
+        utils::load_sol_usd_price(&ctx.accounts.price_oracle, &ctx.accounts.global_config)?;
+
+    ctx.accounts.bonding_curve.quote_buy(
+        &ctx.accounts.global_config,
+        amount_in_sol,
+        has_referrer,
+        sol_usd_price.as_ref(),
+    )
+}
+
+pub fn quote_buy_exact_tokens_ix(
+    ctx: Context<Quote>,
+    token_amount: u64,
+    has_referrer: bool,
+) -> Result<TradeQuote> {
+    let sol_usd_price =
+        utils::load_sol_usd_price(&ctx.accounts.price_oracle, &ctx.accounts.global_config)?;
+
+    ctx.accounts.bonding_curve.quote_buy_exact_tokens(
+        &ctx.accounts.global_config,
+        token_amount,
+        has_referrer,
+        sol_usd_price.as_ref(),
+    )
+}
+
+pub fn quote_sell_ix(
+    ctx: Context<Quote>,
+    token_amount: u64,
+    has_referrer: bool,
+) -> Result<TradeQuote> {
+    ctx.accounts
+        .bonding_curve
+        .quote_sell(&ctx.accounts.global_config, token_amount, has_referrer)
+}
+
+pub fn quote_sell_exact_sol_ix(
+    ctx: Context<Quote>,
+    sol_out: u64,
+    has_referrer: bool,
+) -> Result<TradeQuote> {
+    ctx.accounts.bonding_curve.quote_sell_exact_sol(
+        &ctx.accounts.global_config,
+        sol_out,
+        has_referrer,
+    )
+}
+
+pub fn quote_swap_ix(
+    ctx: Context<QuotePool>,
+    amount_in: u64,
+    direction: SwapDirection,
+) -> Result<PoolSwapQuote> {
+    ctx.accounts.pool.quote_swap(amount_in, direction)
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+pub fn register_referrer_ix(ctx: Context<RegisterReferrer>) -> Result<()> {
+    ctx.accounts.referrer_stats.referrer = ctx.accounts.referrer.key();
+    ctx.accounts.referrer_stats.total_referred_volume = 0;
+    ctx.accounts.referrer_stats.total_fees_earned = 0;
+    ctx.accounts.referrer_stats.referred_trades = 0;
+
+    let event = ReferrerRegistered {
+        referrer: ctx.accounts.referrer.key(),
+        referrer_stats: ctx.accounts.referrer_stats.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
@@ -4,16 +4,23 @@ pub fn sell_ix(
     ctx: Context<Sell>,
     amount: u64,
     is_percentage: bool,
-    slippage_basis_points: Option<u64>,
+    min_sol_out: Option<u64>,
+    deadline: Option<TradeDeadline>,
 ) -> Result<()> {
-    let slippage_bps = slippage_basis_points.unwrap_or(DEF_SLIPPAGE_BPS);
+    utils::check_deadline(deadline)?;
+    let min_sol_out = min_sol_out.unwrap_or(0);
 
     let tokens_to_sell = if is_percentage {
         if amount > 10_000 {
             return Err(error!(Errors::InvalidPercentage));
         }
 
-        (ctx.accounts.associated_user.amount * amount) / 10_000
+        rekthub_math::mul_div_floor(
+            ctx.accounts.associated_user.amount,
+            amount,
+            rekthub_math::BPS_DENOMINATOR,
+        )
+        .map_err(Errors::from)?
     } else {
         amount
     };
@@ -26,23 +33,32 @@ pub fn sell_ix(
         return Err(error!(Errors::InvalidAmount));
     }
 
-    let sol_to_receive = ctx
-        .accounts
-        .bonding_curve
-        .get_sell_price(tokens_to_sell, FEE_BPS)?;
+    let referrer = utils::resolve_referrer(
+        &ctx.accounts.seller.key(),
+        &ctx.accounts.referrer,
+        &ctx.accounts.referrer_stats,
+    )?;
 
-    let min_sol_expected = utils::calculate_min_sol_with_slippage(sol_to_receive, slippage_bps);
+    let quote = ctx.accounts.bonding_curve.quote_sell(
+        &ctx.accounts.global_config,
+        tokens_to_sell,
+        referrer.is_some(),
+    )?;
 
-    if sol_to_receive < min_sol_expected {
+    if quote.fees.net < min_sol_out {
         return Err(error!(Errors::SlippageExceeded));
     }
 
-    let gross_sol = (sol_to_receive * 10_000) / (10_000 - FEE_BPS);
-    let fee = gross_sol - sol_to_receive;
+    execute_sell(ctx, quote, referrer)
+}
 
-    if ctx.accounts.bonding_curve.real_sol_reserves < gross_sol {
-        return Err(error!(Errors::InsufficientReserves));
-    }
+/// Moves tokens and SOL for a priced sell and updates the curve reserves.
+pub(crate) fn execute_sell(
+    ctx: Context<Sell>,
+    quote: TradeQuote,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    let fees = quote.fees;
 
     utils::transfer_tokens(
         &ctx.accounts.associated_user,
@@ -50,35 +66,50 @@ pub fn sell_ix(
         &ctx.accounts.seller.to_account_info(),
         &ctx.accounts.mint,
         &ctx.accounts.token_program,
-        tokens_to_sell,
+        quote.token_amount,
         None,
     )?;
 
-    **ctx
-        .accounts
-        .bonding_curve
-        .to_account_info()
-        .try_borrow_mut_lamports()? -= sol_to_receive;
-    **ctx.accounts.seller.try_borrow_mut_lamports()? += sol_to_receive;
+    utils::move_lamports(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        fees.net,
+    )?;
+    utils::move_lamports(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        &ctx.accounts.global_fee_vault.to_account_info(),
+        fees.protocol_fee,
+    )?;
+    utils::move_lamports(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        &ctx.accounts.creator_fee_vault.to_account_info(),
+        fees.creator_fee,
+    )?;
 
-    **ctx
-        .accounts
-        .bonding_curve
-        .to_account_info()
-        .try_borrow_mut_lamports()? -= fee;
-    **ctx.accounts.global_fee_vault.try_borrow_mut_lamports()? += fee;
+    if let Some(referrer_stats) = &mut ctx.accounts.referrer_stats {
+        utils::move_lamports(
+            &ctx.accounts.bonding_curve.to_account_info(),
+            &referrer_stats.to_account_info(),
+            fees.referral_fee,
+        )?;
+
+        referrer_stats.record_trade(fees.gross, fees.referral_fee)?;
+    }
 
-    ctx.accounts.bonding_curve.virtual_token_reserves += tokens_to_sell;
-    ctx.accounts.bonding_curve.real_token_reserves += tokens_to_sell;
-    ctx.accounts.bonding_curve.virtual_sol_reserves -= gross_sol;
-    ctx.accounts.bonding_curve.real_sol_reserves -= gross_sol;
+    ctx.accounts
+        .bonding_curve
+        .apply_sell(fees.gross, quote.token_amount)?;
 
     let event = OnSellEvent {
         seller: ctx.accounts.seller.key(),
         mint: ctx.accounts.mint.key(),
-        tokens_sold: tokens_to_sell,
-        sol_received: sol_to_receive,
-        fee_paid: fee,
+        tokens_sold: quote.token_amount,
+        sol_received: fees.net,
+        fee_bps: quote.fee_bps,
+        fee_paid: fees.fee,
+        creator_fee: fees.creator_fee,
+        referrer,
+        referral_fee: fees.referral_fee,
         virtual_sol_reserves: ctx.accounts.bonding_curve.virtual_sol_reserves,
         virtual_token_reserves: ctx.accounts.bonding_curve.virtual_token_reserves,
         real_sol_reserves: ctx.accounts.bonding_curve.real_sol_reserves,
@@ -0,0 +1,32 @@
+use super::*;
+
+pub fn sell_for_exact_sol_ix(
+    ctx: Context<Sell>,
+    sol_out: u64,
+    max_tokens_in: u64,
+    deadline: Option<TradeDeadline>,
+) -> Result<()> {
+    utils::check_deadline(deadline)?;
+
+    let referrer = utils::resolve_referrer(
+        &ctx.accounts.seller.key(),
+        &ctx.accounts.referrer,
+        &ctx.accounts.referrer_stats,
+    )?;
+
+    let quote = ctx.accounts.bonding_curve.quote_sell_exact_sol(
+        &ctx.accounts.global_config,
+        sol_out,
+        referrer.is_some(),
+    )?;
+
+    if ctx.accounts.associated_user.amount < quote.token_amount {
+        return Err(error!(Errors::InsufficientTokenBalance));
+    }
+
+    if quote.token_amount > max_tokens_in {
+        return Err(error!(Errors::SlippageExceeded));
+    }
+
+    sell::execute_sell(ctx, quote, referrer)
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+pub fn unlock_lp_ix(ctx: Context<UnlockLp>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let unlock_at = ctx
+        .accounts
+        .lp_lock
+        .unlock_at
+        .ok_or(Errors::LpStillLocked)?;
+    require!(timestamp >= unlock_at, Errors::LpStillLocked);
+
+    let amount = ctx.accounts.lp_lock_token.amount;
+    require!(amount > 0, Errors::InsufficientTokenBalance);
+
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[LP_LOCK.as_bytes(), mint_key.as_ref(), &[ctx.bumps.lp_lock]]];
+
+    utils::transfer_tokens(
+        &ctx.accounts.lp_lock_token,
+        &ctx.accounts.beneficiary_lp_token,
+        &ctx.accounts.lp_lock.to_account_info(),
+        &ctx.accounts.lp_mint,
+        &ctx.accounts.token_program,
+        amount,
+        Some(signer_seeds),
+    )?;
+
+    ctx.accounts.lp_lock.amount = 0;
+
+    let event = LpUnlocked {
+        mint: ctx.accounts.mint.key(),
+        lp_lock: ctx.accounts.lp_lock.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        amount,
+        timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use super::*;
+
+pub fn update_config_ix(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    if !global_config.apply(&params) {
+        return Ok(());
+    }
+
+    global_config.validate()?;
+
+    let event = global_config.updated_event(Clock::get()?.unix_timestamp);
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
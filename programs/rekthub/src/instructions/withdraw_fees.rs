@@ -0,0 +1,38 @@
+use super::*;
+
+pub fn withdraw_fees_ix(ctx: Context<WithdrawFees>, amount: Option<u64>) -> Result<()> {
+    let vault_balance = ctx.accounts.global_fee_vault.lamports();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+
+    let available = vault_balance.saturating_sub(rent_exempt_minimum);
+    let amount = amount.unwrap_or(available);
+
+    require!(amount > 0, Errors::InvalidAmount);
+    require!(amount <= available, Errors::InsufficientFunds);
+
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[GLOBAL_FEE_VAULT.as_bytes(), &[ctx.bumps.global_fee_vault]]];
+
+    utils::transfer_sol(
+        &ctx.accounts.global_fee_vault.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+        Some(signer_seeds),
+    )?;
+
+    let event = FeesWithdrawn {
+        authority: ctx.accounts.authority.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        remaining_balance: ctx.accounts.global_fee_vault.lamports(),
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    emit_cpi!(event);
+
+    // Fallback event emitter
+    emit!(event);
+
+    Ok(())
+}
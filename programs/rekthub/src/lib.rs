@@ -1,7 +1,7 @@
 #![allow(deprecated)]
 use anchor_lang::{prelude::*, solana_program::clock::Clock, system_program};
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, AssociatedToken},
     token::{self, Token},
     token_interface::{self, mint_to, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
 };
@@ -10,6 +10,10 @@ use mpl_token_metadata::{
     ID as MetadataProgram,
 };
 use raydium_cp_swap;
+use rekthub_math::{
+    CurveModel, CurveState, FeeConfig, FeeSchedule, GraduationTrigger, LaunchWindow, MathError,
+    PoolState, PoolSwapQuote, PricingCurve, SolUsdPrice, TradeQuote,
+};
 
 pub mod constants;
 pub mod contexts;
@@ -29,30 +33,134 @@ declare_id!("rekthB7rsdX7nCT8aQi977noT72AtkqVDWt1Y9VmZFG");
 pub mod rekthub {
     use super::*;
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config_ix(ctx)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        instructions::update_config_ix(ctx, params)
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: Option<u64>) -> Result<()> {
+        instructions::withdraw_fees_ix(ctx, amount)
+    }
+
     pub fn initialize(
         ctx: Context<Initialize>,
         name: String,
         symbol: String,
         uri: String,
+        curve_params: Option<CurveParams>,
+        curve_model: Option<CurveModel>,
+        migration_target: Option<MigrationTarget>,
     ) -> Result<()> {
-        instructions::initialize_ix(ctx, name, symbol, uri)
+        instructions::initialize_ix(
+            ctx,
+            name,
+            symbol,
+            uri,
+            curve_params,
+            curve_model,
+            migration_target,
+        )
     }
 
+    /// `min_tokens_out` is an absolute bound quoted off-chain, in raw token units. `None` skips
+    /// the check and only exists so integrations can migrate to quoting their own bound.
     pub fn buy(
         ctx: Context<Buy>,
         amount_in_sol: u64,
-        slippage_basis_points: Option<u64>,
+        min_tokens_out: Option<u64>,
+        deadline: Option<TradeDeadline>,
     ) -> Result<()> {
-        instructions::buy_ix(ctx, amount_in_sol, slippage_basis_points)
+        instructions::buy_ix(ctx, amount_in_sol, min_tokens_out, deadline)
     }
 
+    pub fn buy_exact_tokens(
+        ctx: Context<Buy>,
+        token_amount: u64,
+        max_sol_cost: u64,
+        deadline: Option<TradeDeadline>,
+    ) -> Result<()> {
+        instructions::buy_exact_tokens_ix(ctx, token_amount, max_sol_cost, deadline)
+    }
+
+    /// `min_sol_out` is an absolute bound on the SOL received after fees, see `buy`.
     pub fn sell(
         ctx: Context<Sell>,
         amount: u64,
         is_percentage: bool,
-        slippage_basis_points: Option<u64>,
+        min_sol_out: Option<u64>,
+        deadline: Option<TradeDeadline>,
     ) -> Result<()> {
-        instructions::sell_ix(ctx, amount, is_percentage, slippage_basis_points)
+        instructions::sell_ix(ctx, amount, is_percentage, min_sol_out, deadline)
+    }
+
+    pub fn sell_for_exact_sol(
+        ctx: Context<Sell>,
+        sol_out: u64,
+        max_tokens_in: u64,
+        deadline: Option<TradeDeadline>,
+    ) -> Result<()> {
+        instructions::sell_for_exact_sol_ix(ctx, sol_out, max_tokens_in, deadline)
+    }
+
+    /// Read-only quotes running the same pricing as the trade instructions, returned through
+    /// return data so clients can simulate them. `has_referrer` only changes the fee split.
+    pub fn quote_buy(
+        ctx: Context<Quote>,
+        amount_in_sol: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        instructions::quote_buy_ix(ctx, amount_in_sol, has_referrer)
+    }
+
+    pub fn quote_buy_exact_tokens(
+        ctx: Context<Quote>,
+        token_amount: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        instructions::quote_buy_exact_tokens_ix(ctx, token_amount, has_referrer)
+    }
+
+    pub fn quote_sell(
+        ctx: Context<Quote>,
+        token_amount: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        instructions::quote_sell_ix(ctx, token_amount, has_referrer)
+    }
+
+    pub fn quote_sell_exact_sol(
+        ctx: Context<Quote>,
+        sol_out: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        instructions::quote_sell_exact_sol_ix(ctx, sol_out, has_referrer)
+    }
+
+    pub fn quote_swap(
+        ctx: Context<QuotePool>,
+        amount_in: u64,
+        direction: SwapDirection,
+    ) -> Result<PoolSwapQuote> {
+        instructions::quote_swap_ix(ctx, amount_in, direction)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer_ix(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees_ix(ctx)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees_ix(ctx)
+    }
+
+    pub fn unlock_lp(ctx: Context<UnlockLp>) -> Result<()> {
+        instructions::unlock_lp_ix(ctx)
     }
 
     pub fn prepare_curve_migration(ctx: Context<PrepareCurveMigration>) -> Result<()> {
@@ -62,4 +170,58 @@ pub mod rekthub {
     pub fn initialize_raydium_pool(ctx: Context<InitializeRaydiumPool>) -> Result<()> {
         instructions::migrations::raydium::initialize_pool(ctx)
     }
+
+    pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrations::migrate_ix(ctx)
+    }
+
+    pub fn migrate_meteora(ctx: Context<MigrateMeteora>) -> Result<()> {
+        instructions::migrations::migrate_meteora_ix(ctx)
+    }
+
+    pub fn migrate_to_pool(ctx: Context<MigrateToPool>) -> Result<()> {
+        instructions::migrations::migrate_to_pool_ix(ctx)
+    }
+
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount_in: u64,
+        min_amount_out: u64,
+        direction: SwapDirection,
+        deadline: Option<TradeDeadline>,
+    ) -> Result<()> {
+        instructions::swap_ix(ctx, amount_in, min_amount_out, direction, deadline)
+    }
+
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        max_sol_amount: u64,
+        max_token_amount: u64,
+        min_lp_amount: u64,
+        deadline: Option<TradeDeadline>,
+    ) -> Result<()> {
+        instructions::add_liquidity_ix(
+            ctx,
+            max_sol_amount,
+            max_token_amount,
+            min_lp_amount,
+            deadline,
+        )
+    }
+
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        lp_amount: u64,
+        min_sol_amount: u64,
+        min_token_amount: u64,
+        deadline: Option<TradeDeadline>,
+    ) -> Result<()> {
+        instructions::remove_liquidity_ix(
+            ctx,
+            lp_amount,
+            min_sol_amount,
+            min_token_amount,
+            deadline,
+        )
+    }
 }
@@ -1,5 +1,450 @@
 use super::*;
 
+use instructions::migrations::{DexAdapter, MeteoraDamm, RaydiumCpmm, RekthubPool};
+
+#[account]
+#[derive(InitSpace, PartialEq)]
+pub struct GlobalConfig {
+    pub authority: Pubkey,
+
+    pub fee_schedule: FeeSchedule,
+    pub launch_window: LaunchWindow,
+    pub graduation_trigger: GraduationTrigger,
+    pub price_oracle: Pubkey,
+    pub oracle_max_age_secs: u64,
+    pub oracle_max_confidence_bps: u64,
+    pub creator_fee_share_bps: u64,
+    pub referral_fee_share_bps: u64,
+    pub token_initialisation_fee: u64,
+    pub migration_fee: u64,
+    pub migration_reward: u64,
+    pub lp_disposition: LpDisposition,
+    pub meteora_pool_config: Pubkey,
+
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigParams {
+    pub new_authority: Option<Pubkey>,
+
+    pub fee_schedule: Option<FeeSchedule>,
+    pub launch_window: Option<LaunchWindow>,
+    pub graduation_trigger: Option<GraduationTrigger>,
+    pub price_oracle: Option<Pubkey>,
+    pub oracle_max_age_secs: Option<u64>,
+    pub oracle_max_confidence_bps: Option<u64>,
+    pub creator_fee_share_bps: Option<u64>,
+    pub referral_fee_share_bps: Option<u64>,
+    pub token_initialisation_fee: Option<u64>,
+    pub migration_fee: Option<u64>,
+    pub migration_reward: Option<u64>,
+    pub lp_disposition: Option<LpDisposition>,
+    pub meteora_pool_config: Option<Pubkey>,
+
+    pub initial_virtual_sol_reserves: Option<u64>,
+    pub initial_virtual_token_reserves: Option<u64>,
+    pub initial_real_token_reserves: Option<u64>,
+    pub token_total_supply: Option<u64>,
+}
+
+impl GlobalConfig {
+    pub fn set_defaults(&mut self, authority: Pubkey) {
+        self.authority = authority;
+
+        self.fee_schedule = FeeSchedule::flat(FEE_BPS);
+        self.launch_window = LaunchWindow::disabled();
+        self.graduation_trigger = GraduationTrigger::TokensSoldOut;
+        self.price_oracle = Pubkey::default();
+        self.oracle_max_age_secs = ORACLE_MAX_AGE_SECS;
+        self.oracle_max_confidence_bps = ORACLE_MAX_CONFIDENCE_BPS;
+        self.creator_fee_share_bps = CREATOR_FEE_SHARE_BPS;
+        self.referral_fee_share_bps = REFERRAL_FEE_SHARE_BPS;
+        self.token_initialisation_fee = TOKEN_INITIALISATION_FEE;
+        self.migration_fee = MIGRATION_FEE;
+        self.migration_reward = MIGRATION_REWARD;
+        self.lp_disposition = LpDisposition::Burn;
+        self.meteora_pool_config = Pubkey::default();
+
+        self.initial_virtual_sol_reserves = VIRTUAL_SOL_RESERVES;
+        self.initial_virtual_token_reserves = VIRTUAL_TOKEN_RESERVES;
+        self.initial_real_token_reserves = REAL_TOKEN_RESERVES;
+        self.token_total_supply = TOTAL_TOKEN_SUPPLY;
+    }
+
+    /// Applies every `Some` field of `params` and returns whether anything changed.
+    pub fn apply(&mut self, params: &UpdateConfigParams) -> bool {
+        let before = self.clone();
+
+        if let Some(authority) = params.new_authority {
+            self.authority = authority;
+        }
+        if let Some(fee_schedule) = params.fee_schedule {
+            self.fee_schedule = fee_schedule;
+        }
+        if let Some(launch_window) = params.launch_window {
+            self.launch_window = launch_window;
+        }
+        if let Some(graduation_trigger) = params.graduation_trigger {
+            self.graduation_trigger = graduation_trigger;
+        }
+        if let Some(price_oracle) = params.price_oracle {
+            self.price_oracle = price_oracle;
+        }
+        if let Some(max_age_secs) = params.oracle_max_age_secs {
+            self.oracle_max_age_secs = max_age_secs;
+        }
+        if let Some(max_confidence_bps) = params.oracle_max_confidence_bps {
+            self.oracle_max_confidence_bps = max_confidence_bps;
+        }
+        if let Some(share_bps) = params.creator_fee_share_bps {
+            self.creator_fee_share_bps = share_bps;
+        }
+        if let Some(share_bps) = params.referral_fee_share_bps {
+            self.referral_fee_share_bps = share_bps;
+        }
+        if let Some(fee) = params.token_initialisation_fee {
+            self.token_initialisation_fee = fee;
+        }
+        if let Some(fee) = params.migration_fee {
+            self.migration_fee = fee;
+        }
+        if let Some(reward) = params.migration_reward {
+            self.migration_reward = reward;
+        }
+        if let Some(lp_disposition) = params.lp_disposition {
+            self.lp_disposition = lp_disposition;
+        }
+        if let Some(pool_config) = params.meteora_pool_config {
+            self.meteora_pool_config = pool_config;
+        }
+        if let Some(reserves) = params.initial_virtual_sol_reserves {
+            self.initial_virtual_sol_reserves = reserves;
+        }
+        if let Some(reserves) = params.initial_virtual_token_reserves {
+            self.initial_virtual_token_reserves = reserves;
+        }
+        if let Some(reserves) = params.initial_real_token_reserves {
+            self.initial_real_token_reserves = reserves;
+        }
+        if let Some(supply) = params.token_total_supply {
+            self.token_total_supply = supply;
+        }
+
+        before != *self
+    }
+
+    pub fn fee_config(&self) -> FeeConfig {
+        FeeConfig {
+            fee_schedule: self.fee_schedule,
+            launch_window: self.launch_window,
+            creator_fee_share_bps: self.creator_fee_share_bps,
+            referral_fee_share_bps: self.referral_fee_share_bps,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.fee_schedule.max_fee_bps() <= MAX_FEE_BPS,
+            Errors::InvalidConfig
+        );
+        require!(
+            self.fee_schedule.taper_progress_bps <= rekthub_math::BPS_DENOMINATOR,
+            Errors::InvalidConfig
+        );
+        require!(
+            self.launch_window.start_fee_bps <= MAX_LAUNCH_FEE_BPS,
+            Errors::InvalidConfig
+        );
+        require!(
+            self.creator_fee_share_bps + self.referral_fee_share_bps <= MAX_FEE_SHARE_BPS,
+            Errors::InvalidConfig
+        );
+        require!(
+            self.migration_reward <= self.migration_fee,
+            Errors::InvalidConfig
+        );
+        if let LpDisposition::Lock {
+            unlock_after_secs: Some(unlock_after_secs),
+        } = self.lp_disposition
+        {
+            require!(unlock_after_secs >= 0, Errors::InvalidConfig);
+        }
+        require!(
+            self.oracle_max_confidence_bps <= rekthub_math::BPS_DENOMINATOR,
+            Errors::InvalidConfig
+        );
+        if let GraduationTrigger::MarketCapUsd(_) = self.graduation_trigger {
+            require!(
+                self.price_oracle != Pubkey::default(),
+                Errors::InvalidConfig
+            );
+        }
+
+        // Default launches migrate to Raydium, which holds back the most for pool creation
+        self.curve_params().validate(
+            &CurveModel::ConstantProduct,
+            &self.graduation_trigger,
+            self.migration_fee,
+            &MigrationTarget::RaydiumCpmm,
+        )
+    }
+
+    pub fn curve_params(&self) -> CurveParams {
+        CurveParams {
+            virtual_sol_reserves: self.initial_virtual_sol_reserves,
+            virtual_token_reserves: self.initial_virtual_token_reserves,
+            real_token_reserves: self.initial_real_token_reserves,
+            total_token_supply: self.token_total_supply,
+        }
+    }
+
+    pub fn updated_event(&self, timestamp: i64) -> ConfigUpdated {
+        ConfigUpdated {
+            authority: self.authority,
+            fee_schedule: self.fee_schedule,
+            launch_window: self.launch_window,
+            graduation_trigger: self.graduation_trigger,
+            price_oracle: self.price_oracle,
+            oracle_max_age_secs: self.oracle_max_age_secs,
+            oracle_max_confidence_bps: self.oracle_max_confidence_bps,
+            creator_fee_share_bps: self.creator_fee_share_bps,
+            referral_fee_share_bps: self.referral_fee_share_bps,
+            token_initialisation_fee: self.token_initialisation_fee,
+            migration_fee: self.migration_fee,
+            migration_reward: self.migration_reward,
+            lp_disposition: self.lp_disposition,
+            meteora_pool_config: self.meteora_pool_config,
+            initial_virtual_sol_reserves: self.initial_virtual_sol_reserves,
+            initial_virtual_token_reserves: self.initial_virtual_token_reserves,
+            initial_real_token_reserves: self.initial_real_token_reserves,
+            token_total_supply: self.token_total_supply,
+            timestamp,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CurveParams {
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_token_reserves: u64,
+    pub total_token_supply: u64,
+}
+
+impl CurveParams {
+    /// Rejects curves that could not be traded to completion and then migrated.
+    pub fn validate(
+        &self,
+        curve_model: &CurveModel,
+        graduation_trigger: &GraduationTrigger,
+        migration_fee: u64,
+        migration_target: &MigrationTarget,
+    ) -> Result<()> {
+        curve_model.validate().map_err(Errors::from)?;
+        graduation_trigger.validate().map_err(Errors::from)?;
+
+        require!(
+            (MIN_VIRTUAL_SOL_RESERVES..=MAX_VIRTUAL_SOL_RESERVES)
+                .contains(&self.virtual_sol_reserves),
+            Errors::InvalidCurveParams
+        );
+        require!(
+            (MIN_TOTAL_TOKEN_SUPPLY..=MAX_TOTAL_TOKEN_SUPPLY).contains(&self.total_token_supply),
+            Errors::InvalidCurveParams
+        );
+
+        let supply = self.total_token_supply as u128;
+        let min_real_token_reserves = supply * MIN_REAL_TOKEN_RESERVES_BPS as u128 / 10_000;
+        let max_real_token_reserves = supply * MAX_REAL_TOKEN_RESERVES_BPS as u128 / 10_000;
+        require!(
+            (min_real_token_reserves..=max_real_token_reserves)
+                .contains(&(self.real_token_reserves as u128)),
+            Errors::InvalidCurveParams
+        );
+        require!(
+            self.real_token_reserves < self.virtual_token_reserves,
+            Errors::InvalidCurveParams
+        );
+
+        // A USD target moves with the SOL price, so only the sold-out bound can be checked here
+        let graduation_trigger = match graduation_trigger {
+            GraduationTrigger::MarketCapUsd(_) => GraduationTrigger::TokensSoldOut,
+            trigger => *trigger,
+        };
+
+        let migration_floor =
+            migration_fee.saturating_add(migration_target.pool_creation_reserve());
+
+        // SOL held by the curve once trading reaches the graduation trigger
+        let initial_state = CurveState {
+            virtual_sol_reserves: self.virtual_sol_reserves,
+            virtual_token_reserves: self.virtual_token_reserves,
+            real_sol_reserves: REAL_SOL_RESERVES,
+            real_token_reserves: self.real_token_reserves,
+            initial_real_token_reserves: self.real_token_reserves,
+            total_token_supply: self.total_token_supply,
+            curve_model: *curve_model,
+            graduation_trigger,
+            min_graduation_sol_reserves: migration_floor,
+            is_complete: false,
+            created_slot: 0,
+        };
+        let tokens_to_graduation = initial_state
+            .tokens_to_graduation()
+            .map_err(|_| error!(Errors::InvalidCurveParams))?;
+        require!(tokens_to_graduation > 0, Errors::InvalidCurveParams);
+
+        let graduation_sol_reserves = initial_state
+            .get_buy_cost(tokens_to_graduation)
+            .and_then(|cost| rekthub_math::add(REAL_SOL_RESERVES, cost))
+            .map_err(|_| error!(Errors::InvalidCurveParams))?;
+        require!(
+            graduation_sol_reserves > migration_floor,
+            Errors::InvalidCurveParams
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum TradeDeadline {
+    UnixTimestamp(i64),
+    Slot(u64),
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReferrerStats {
+    pub referrer: Pubkey,
+    pub total_referred_volume: u64,
+    pub total_fees_earned: u64,
+    pub referred_trades: u64,
+}
+
+impl ReferrerStats {
+    pub fn record_trade(&mut self, volume: u64, referral_fee: u64) -> Result<()> {
+        self.total_referred_volume = self
+            .total_referred_volume
+            .checked_add(volume)
+            .ok_or(Errors::MathOverflow)?;
+        self.total_fees_earned = self
+            .total_fees_earned
+            .checked_add(referral_fee)
+            .ok_or(Errors::MathOverflow)?;
+        self.referred_trades = self
+            .referred_trades
+            .checked_add(1)
+            .ok_or(Errors::MathOverflow)?;
+
+        Ok(())
+    }
+}
+
+/// Buys made by one wallet on one curve, counted against the launch window's per-wallet limit.
+#[account]
+#[derive(InitSpace)]
+pub struct BuyerRecord {
+    pub buyer: Pubkey,
+    pub mint: Pubkey,
+    pub buy_count: u64,
+}
+
+impl BuyerRecord {
+    pub fn record_buy(&mut self) -> Result<()> {
+        self.buy_count = self.buy_count.checked_add(1).ok_or(Errors::MathOverflow)?;
+
+        Ok(())
+    }
+}
+
+/// What happens to the LP tokens a curve's pool mints at migration.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum LpDisposition {
+    /// Burned, so the liquidity can never be withdrawn.
+    Burn,
+    /// Held by the curve's `LpLock`, releasable to its beneficiary `unlock_after_secs` after
+    /// migration, or never when `None`.
+    Lock { unlock_after_secs: Option<i64> },
+}
+
+/// DEX a curve's liquidity moves into when it migrates, chosen at `initialize`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum MigrationTarget {
+    /// Raydium CPMM pool, created by `migrate` or `initialize_raydium_pool`.
+    RaydiumCpmm,
+    /// Meteora DAMM constant-product pool using `GlobalConfig.meteora_pool_config`, created by
+    /// `migrate_meteora`.
+    MeteoraDamm,
+    /// RektHub's own constant-product `Pool`, created by `migrate_to_pool` without any external
+    /// CPI.
+    RekthubPool,
+}
+
+impl MigrationTarget {
+    /// SOL migrating through this target holds back for pool creation, on top of the migration
+    /// fee.
+    pub fn pool_creation_reserve(&self) -> u64 {
+        match self {
+            MigrationTarget::RaydiumCpmm => RaydiumCpmm::POOL_CREATION_RESERVE,
+            MigrationTarget::MeteoraDamm => MeteoraDamm::POOL_CREATION_RESERVE,
+            MigrationTarget::RekthubPool => RekthubPool::POOL_CREATION_RESERVE,
+        }
+    }
+}
+
+/// Lifecycle of a bonding curve. Each status only moves forward to the next one.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum CurveStatus {
+    /// Open for buys and sells.
+    Trading,
+    /// Graduation trigger reached; trading is closed until the curve migrates.
+    Complete,
+    /// Tokens and SOL moved to the migration authority.
+    MigrationPrepared,
+    /// Liquidity deposited into a DEX pool.
+    Migrated,
+}
+
+impl CurveStatus {
+    /// Fails unless `next` directly follows this status.
+    pub fn check_transition(&self, next: CurveStatus) -> Result<()> {
+        match (self, next) {
+            (CurveStatus::Trading, CurveStatus::Complete)
+            | (CurveStatus::Complete, CurveStatus::MigrationPrepared)
+            | (CurveStatus::MigrationPrepared, CurveStatus::Migrated) => Ok(()),
+            (CurveStatus::Trading, _) => err!(Errors::BondingCurveNotComplete),
+            (CurveStatus::Complete, CurveStatus::Migrated) => {
+                err!(Errors::CurveMigrationNotPrepared)
+            }
+            (CurveStatus::MigrationPrepared, CurveStatus::MigrationPrepared) => {
+                err!(Errors::CurveMigrationAlreadyPrepared)
+            }
+            (CurveStatus::Migrated, _) => err!(Errors::BondingCurveMigrated),
+            _ => err!(Errors::InvalidCurveStatusTransition),
+        }
+    }
+}
+
+/// LP tokens of a migrated curve's pool, held until `unlock_at`.
+#[account]
+#[derive(InitSpace)]
+pub struct LpLock {
+    pub mint: Pubkey,
+    pub pool_state: Pubkey,
+    pub lp_mint: Pubkey,
+    /// The curve's creator, never the protocol authority, so locked liquidity cannot be pulled
+    /// by the protocol.
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    /// `None` keeps the LP tokens locked forever.
+    pub unlock_at: Option<i64>,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct BondingCurve {
@@ -14,51 +459,214 @@ pub struct BondingCurve {
     pub virtual_token_reserves: u64,
 
     pub total_token_supply: u64,
-    pub is_bonding_curve_complete: bool,
-    pub has_curve_migrated: bool,
+    pub initial_real_token_reserves: u64,
+    pub curve_model: CurveModel,
+    pub graduation_trigger: GraduationTrigger,
+    pub lp_disposition: LpDisposition,
+    pub migration_target: MigrationTarget,
+    /// Snapshotted at `initialize`, so a later `update_config` cannot strand a launched curve
+    pub migration_fee: u64,
+    pub migration_reward: u64,
+
+    pub status: CurveStatus,
+
+    pub created_slot: u64,
+    pub created_at: i64,
 }
 
 impl BondingCurve {
-    pub fn get_buy_price(&self, amount: u64) -> Result<u64> {
-        if self.is_bonding_curve_complete {
-            return err!(Errors::BondingCurveComplete);
+    pub fn curve_state(&self) -> CurveState {
+        CurveState {
+            virtual_sol_reserves: self.virtual_sol_reserves,
+            virtual_token_reserves: self.virtual_token_reserves,
+            real_sol_reserves: self.real_sol_reserves,
+            real_token_reserves: self.real_token_reserves,
+            initial_real_token_reserves: self.initial_real_token_reserves,
+            total_token_supply: self.total_token_supply,
+            curve_model: self.curve_model,
+            graduation_trigger: self.graduation_trigger,
+            min_graduation_sol_reserves: self.migration_floor(),
+            is_complete: self.status != CurveStatus::Trading,
+            created_slot: self.created_slot,
         }
+    }
 
-        if amount == 0 {
-            return Ok(0);
-        }
+    /// SOL the curve must hold to pay its migration fee and create its pool, so a USD target
+    /// that is met early cannot complete a curve its migration would then fail on.
+    pub fn migration_floor(&self) -> u64 {
+        self.migration_fee
+            .saturating_add(self.migration_target.pool_creation_reserve())
+    }
 
-        let n: u128 = (self.virtual_sol_reserves as u128) * (self.virtual_token_reserves as u128);
-        let i: u128 = (self.virtual_sol_reserves as u128) + (amount as u128);
-        let r: u128 = n / i + 1;
-        let s: u128 = (self.virtual_token_reserves as u128) - r;
+    /// Curve state with a USD graduation target converted to lamports at `sol_usd_price`.
+    pub fn priced_curve_state(&self, sol_usd_price: Option<&SolUsdPrice>) -> Result<CurveState> {
+        let mut state = self.curve_state();
+        state.graduation_trigger = self
+            .graduation_trigger
+            .resolve(sol_usd_price)
+            .map_err(Errors::from)?;
 
-        let s_u64 = s as u64;
-        Ok(std::cmp::min(s_u64, self.real_token_reserves))
+        Ok(state)
     }
 
-    pub fn get_sell_price(&self, amount: u64, fee_basis_points: u64) -> Result<u64> {
-        if self.is_bonding_curve_complete {
-            return err!(Errors::BondingCurveComplete);
-        }
+    /// Implied market cap in USD, with `USD_DECIMALS` decimals.
+    pub fn market_cap_usd(&self, sol_usd_price: &SolUsdPrice) -> Result<u64> {
+        Ok(self
+            .curve_state()
+            .market_cap()
+            .and_then(|market_cap| sol_usd_price.lamports_to_usd(market_cap))
+            .map_err(Errors::from)?)
+    }
 
-        if amount == 0 {
-            return Ok(0);
-        }
+    pub fn quote_buy(
+        &self,
+        config: &GlobalConfig,
+        amount_in_sol: u64,
+        has_referrer: bool,
+        sol_usd_price: Option<&SolUsdPrice>,
+    ) -> Result<TradeQuote> {
+        Ok(self
+            .priced_curve_state(sol_usd_price)?
+            .quote_buy(
+                &config.fee_config(),
+                amount_in_sol,
+                has_referrer,
+                Clock::get()?.slot,
+            )
+            .map_err(Errors::from)?)
+    }
+
+    pub fn quote_buy_exact_tokens(
+        &self,
+        config: &GlobalConfig,
+        token_amount: u64,
+        has_referrer: bool,
+        sol_usd_price: Option<&SolUsdPrice>,
+    ) -> Result<TradeQuote> {
+        Ok(self
+            .priced_curve_state(sol_usd_price)?
+            .quote_buy_exact_tokens(
+                &config.fee_config(),
+                token_amount,
+                has_referrer,
+                Clock::get()?.slot,
+            )
+            .map_err(Errors::from)?)
+    }
+
+    pub fn quote_sell(
+        &self,
+        config: &GlobalConfig,
+        token_amount: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        Ok(self
+            .curve_state()
+            .quote_sell(&config.fee_config(), token_amount, has_referrer)
+            .map_err(Errors::from)?)
+    }
+
+    pub fn quote_sell_exact_sol(
+        &self,
+        config: &GlobalConfig,
+        sol_out: u64,
+        has_referrer: bool,
+    ) -> Result<TradeQuote> {
+        Ok(self
+            .curve_state()
+            .quote_sell_exact_sol(&config.fee_config(), sol_out, has_referrer)
+            .map_err(Errors::from)?)
+    }
+
+    /// Books a buy of `tokens` for `sol` into the reserves.
+    pub fn apply_buy(&mut self, sol: u64, tokens: u64) -> Result<()> {
+        let mut state = self.curve_state();
+        state.apply_buy(sol, tokens).map_err(Errors::from)?;
+        self.store(&state);
+
+        Ok(())
+    }
+
+    /// Books a sell of `tokens` for `sol` into the reserves.
+    pub fn apply_sell(&mut self, sol: u64, tokens: u64) -> Result<()> {
+        let mut state = self.curve_state();
+        state.apply_sell(sol, tokens).map_err(Errors::from)?;
+        self.store(&state);
+
+        Ok(())
+    }
 
-        let n: u128 = ((amount as u128) * (self.virtual_sol_reserves as u128))
-            / ((self.virtual_token_reserves as u128) + (amount as u128));
+    fn store(&mut self, state: &CurveState) {
+        self.virtual_sol_reserves = state.virtual_sol_reserves;
+        self.virtual_token_reserves = state.virtual_token_reserves;
+        self.real_sol_reserves = state.real_sol_reserves;
+        self.real_token_reserves = state.real_token_reserves;
+    }
 
-        let fee: u128 = (n * fee_basis_points as u128) / 10_000;
+    pub fn transition_to(&mut self, next: CurveStatus) -> Result<()> {
+        self.status.check_transition(next)?;
+        self.status = next;
 
-        Ok((n - fee) as u64)
+        Ok(())
     }
 
-    pub fn is_ready_for_graduation(&self) -> Result<bool> {
-        if self.is_bonding_curve_complete {
-            return Ok(true);
+    pub fn is_ready_for_graduation(&self, sol_usd_price: Option<&SolUsdPrice>) -> Result<bool> {
+        Ok(self
+            .priced_curve_state(sol_usd_price)?
+            .is_ready_for_graduation()
+            .map_err(Errors::from)?)
+    }
+}
+
+/// Constant-product pool a curve with the `RekthubPool` target migrates into. Holds its SOL as
+/// lamports above rent and its tokens in its associated token account.
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub mint: Pubkey,
+    pub lp_mint: Pubkey,
+
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    pub lp_supply: u64,
+    pub fee_bps: u64,
+
+    pub created_at: i64,
+}
+
+impl Pool {
+    pub fn pool_state(&self) -> PoolState {
+        PoolState {
+            sol_reserves: self.sol_reserves,
+            token_reserves: self.token_reserves,
+            lp_supply: self.lp_supply,
+            fee_bps: self.fee_bps,
         }
+    }
+
+    /// Prices a swap of `amount_in` exactly as `swap` would execute it.
+    pub fn quote_swap(&self, amount_in: u64, direction: SwapDirection) -> Result<PoolSwapQuote> {
+        let pool_state = self.pool_state();
+        let quote = match direction {
+            SwapDirection::Buy => pool_state.quote_buy(amount_in),
+            SwapDirection::Sell => pool_state.quote_sell(amount_in),
+        };
 
-        Ok(self.real_token_reserves <= 0)
+        Ok(quote.map_err(Errors::from)?)
     }
+
+    pub fn set_pool_state(&mut self, state: &PoolState) {
+        self.sol_reserves = state.sol_reserves;
+        self.token_reserves = state.token_reserves;
+        self.lp_supply = state.lp_supply;
+        self.fee_bps = state.fee_bps;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapDirection {
+    /// SOL in, tokens out.
+    Buy,
+    /// Tokens in, SOL out.
+    Sell,
 }
@@ -1,11 +1,120 @@
 use super::*;
 
-pub fn calculate_min_tokens_with_slippage(amount: u64, basis_points: u64) -> u64 {
-    amount - (amount * basis_points) / 10_000 // Subtract slippage for minimum
+pub fn check_deadline(deadline: Option<TradeDeadline>) -> Result<()> {
+    let Some(deadline) = deadline else {
+        return Ok(());
+    };
+
+    let clock = Clock::get()?;
+    let expired = match deadline {
+        TradeDeadline::UnixTimestamp(timestamp) => clock.unix_timestamp > timestamp,
+        TradeDeadline::Slot(slot) => clock.slot > slot,
+    };
+
+    require!(!expired, Errors::TransactionExpired);
+
+    Ok(())
+}
+
+/// Enforces the launch window's buy size and per-wallet limits on a buy of `sol_amount`.
+pub fn check_launch_window(
+    launch_window: &LaunchWindow,
+    bonding_curve: &BondingCurve,
+    sol_amount: u64,
+    buyer_record: &Option<Account<BuyerRecord>>,
+) -> Result<()> {
+    let elapsed_slots = bonding_curve
+        .curve_state()
+        .elapsed_slots(Clock::get()?.slot);
+
+    if !launch_window.is_active(elapsed_slots) {
+        return Ok(());
+    }
+
+    if launch_window.max_buy_sol > 0 {
+        require!(
+            sol_amount <= launch_window.max_buy_sol,
+            Errors::LaunchWindowBuyTooLarge
+        );
+    }
+
+    if launch_window.max_buys_per_wallet > 0 {
+        let buyer_record = buyer_record.as_ref().ok_or(Errors::BuyerRecordRequired)?;
+        require!(
+            buyer_record.buy_count < launch_window.max_buys_per_wallet,
+            Errors::LaunchWindowBuyLimitReached
+        );
+    }
+
+    Ok(())
+}
+
+/// Reads the SOL/USD price from a Pyth-style price account, rejecting prices that are not
+/// trading, older than `oracle_max_age_secs` or less certain than `oracle_max_confidence_bps`.
+/// Returns `None` when no oracle was passed.
+pub fn load_sol_usd_price(
+    price_oracle: &Option<UncheckedAccount>,
+    global_config: &GlobalConfig,
+) -> Result<Option<SolUsdPrice>> {
+    let Some(price_oracle) = price_oracle else {
+        return Ok(None);
+    };
+
+    let data = price_oracle.try_borrow_data()?;
+    require!(
+        data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN,
+        Errors::InvalidPriceOracle
+    );
+
+    require!(
+        pyth_magic(&data) == PYTH_MAGIC && pyth_account_type(&data) == PYTH_PRICE_ACCOUNT_TYPE,
+        Errors::InvalidPriceOracle
+    );
+    require!(
+        pyth_agg_status(&data) == PYTH_STATUS_TRADING,
+        Errors::PriceOracleUnavailable
+    );
+
+    let price = pyth_agg_price(&data);
+    let confidence = pyth_agg_conf(&data);
+    let expo = pyth_expo(&data);
+    let timestamp = pyth_timestamp(&data);
+    require!(price > 0, Errors::PriceOracleUnavailable);
+
+    let age = Clock::get()?.unix_timestamp.saturating_sub(timestamp);
+    let max_age = i64::try_from(global_config.oracle_max_age_secs).unwrap_or(i64::MAX);
+    require!(age <= max_age, Errors::PriceOracleStale);
+
+    let price = price as u64;
+    require!(
+        (confidence as u128) * (rekthub_math::BPS_DENOMINATOR as u128)
+            <= (price as u128) * (global_config.oracle_max_confidence_bps as u128),
+        Errors::PriceOracleConfidenceTooWide
+    );
+
+    Ok(Some(SolUsdPrice { price, expo }))
 }
 
-pub fn calculate_min_sol_with_slippage(amount: u64, basis_points: u64) -> u64 {
-    amount - (amount * basis_points) / 10_000 // Subtract for minimum
+/// Returns the referrer for a trade, checking it matches its stats account and is not the trader.
+pub fn resolve_referrer<'info>(
+    trader: &Pubkey,
+    referrer: &Option<UncheckedAccount<'info>>,
+    referrer_stats: &Option<Account<'info, ReferrerStats>>,
+) -> Result<Option<Pubkey>> {
+    match (referrer, referrer_stats) {
+        (Some(referrer), Some(referrer_stats)) => {
+            require_keys_eq!(
+                referrer_stats.referrer,
+                referrer.key(),
+                Errors::InvalidReferrer
+            );
+            require_keys_neq!(referrer.key(), *trader, Errors::InvalidReferrer);
+
+            Ok(Some(referrer.key()))
+        }
+        (None, None) => Ok(None),
+        _ => err!(Errors::InvalidReferrer),
+    }
 }
 
 pub fn transfer_sol<'info>(
@@ -29,6 +138,115 @@ pub fn transfer_sol<'info>(
     system_program::transfer(cpi_ctx, amount)
 }
 
+/// Moves lamports out of a program-owned account without a system program CPI.
+pub fn move_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let from_lamports = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(Errors::MathOverflow)?;
+    let to_lamports = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(Errors::MathOverflow)?;
+
+    **from.try_borrow_mut_lamports()? = from_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+
+    Ok(())
+}
+
+/// Accounts involved in burning or locking the LP tokens a new pool minted to `owner`.
+pub struct LpDisposal<'a, 'info> {
+    pub mint: Pubkey,
+    pub pool_state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub lp_mint: &'a AccountInfo<'info>,
+    pub lp_token: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub owner_seeds: &'a [&'a [&'a [u8]]],
+    pub lp_lock: Option<&'a mut Account<'info, LpLock>>,
+    pub lp_lock_token: Option<&'a AccountInfo<'info>>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Burns or locks every LP token in `lp_token` according to `disposition` and returns how many
+/// there were.
+pub fn dispose_lp_tokens(accounts: LpDisposal, disposition: LpDisposition) -> Result<u64> {
+    let amount =
+        token::TokenAccount::try_deserialize(&mut &accounts.lp_token.try_borrow_data()?[..])?
+            .amount;
+
+    match disposition {
+        LpDisposition::Burn => token::burn(
+            CpiContext::new_with_signer(
+                accounts.token_program.clone(),
+                token::Burn {
+                    mint: accounts.lp_mint.clone(),
+                    from: accounts.lp_token.clone(),
+                    authority: accounts.owner.clone(),
+                },
+                accounts.owner_seeds,
+            ),
+            amount,
+        )?,
+        LpDisposition::Lock { unlock_after_secs } => {
+            let lp_lock = accounts.lp_lock.ok_or(Errors::LpLockRequired)?;
+            let lp_lock_token = accounts.lp_lock_token.ok_or(Errors::LpLockRequired)?;
+
+            associated_token::create(CpiContext::new(
+                accounts.associated_token_program.clone(),
+                associated_token::Create {
+                    payer: accounts.payer.clone(),
+                    associated_token: lp_lock_token.clone(),
+                    authority: lp_lock.to_account_info(),
+                    mint: accounts.lp_mint.clone(),
+                    system_program: accounts.system_program.clone(),
+                    token_program: accounts.token_program.clone(),
+                },
+            ))?;
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    accounts.token_program.clone(),
+                    token::Transfer {
+                        from: accounts.lp_token.clone(),
+                        to: lp_lock_token.clone(),
+                        authority: accounts.owner.clone(),
+                    },
+                    accounts.owner_seeds,
+                ),
+                amount,
+            )?;
+
+            let unlock_at = match unlock_after_secs {
+                Some(secs) => Some(
+                    Clock::get()?
+                        .unix_timestamp
+                        .checked_add(secs)
+                        .ok_or(Errors::MathOverflow)?,
+                ),
+                None => None,
+            };
+
+            lp_lock.mint = accounts.mint;
+            lp_lock.pool_state = accounts.pool_state;
+            lp_lock.lp_mint = accounts.lp_mint.key();
+            lp_lock.beneficiary = accounts.beneficiary;
+            lp_lock.amount = amount;
+            lp_lock.unlock_at = unlock_at;
+        }
+    }
+
+    Ok(amount)
+}
+
 pub fn transfer_tokens<'info>(
     from: &InterfaceAccount<'info, TokenAccount>,
     to: &InterfaceAccount<'info, TokenAccount>,
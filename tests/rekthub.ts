@@ -13,12 +13,14 @@ import {
 import {
 	createInitializeMintInstruction,
 	getAssociatedTokenAddressSync,
+	getMinimumBalanceForRentExemptAccount,
 	getMinimumBalanceForRentExemptMint,
 	MINT_SIZE,
 	NATIVE_MINT,
 	TOKEN_PROGRAM_ID,
 } from '@solana/spl-token';
 import { bs58 } from '@coral-xyz/anchor/dist/cjs/utils/bytes';
+import { expect } from 'chai';
 
 describe('rekthub', () => {
 	anchor.setProvider(anchor.AnchorProvider.env());
@@ -28,6 +30,256 @@ describe('rekthub', () => {
 	const creator = Keypair.fromSecretKey(
 		bs58.decode(process.env.WALLET_PRIVATE_KEY)
 	);
+	// Buys and sells through `creator` as its referrer
+	const trader = Keypair.generate();
+	const connection = anchor.AnchorProvider.env().connection;
+	const eventParser = new anchor.EventParser(program.programId, program.coder);
+
+	const pda = (...seeds: (string | PublicKey)[]) =>
+		PublicKey.findProgramAddressSync(
+			seeds.map((seed) => (typeof seed === 'string' ? Buffer.from(seed) : seed.toBuffer())),
+			program.programId
+		)[0];
+	const globalFeeVault = pda('global_fee_vault');
+	const referrerStats = pda('referrer_stats', creator.publicKey);
+	let referralFeesAccrued = 0;
+
+	// Completed curve the two-step Raydium migration tests share, and the SOL `prepare_curve_migration`
+	// moved to its migration authority
+	let raydiumMint: Keypair | undefined;
+	let raydiumPreparedSol = 0;
+	// Completed `rekthubPool` curve the in-house pool tests share
+	let poolMint: Keypair | undefined;
+	// `POOL_FEE_BPS` and `MINIMUM_LIQUIDITY`
+	const poolFeeBps = 25;
+	const minimumLiquidity = 1_000;
+
+	const raydiumProgram = new PublicKey('DRaycpLY18LhpbydsBWbVJtxpNv9oXPgjRSfpF2bWpYb');
+	const [raydiumAmmConfig] = PublicKey.findProgramAddressSync(
+		[Buffer.from('amm_config'), Buffer.from([0, 0])],
+		raydiumProgram
+	);
+	// `RaydiumCpmm::POOL_CREATION_RESERVE`
+	const raydiumPoolCreationReserve = 0.2 * LAMPORTS_PER_SOL;
+
+	// Loads a confirmed transaction's events and the lamport change it made to each account. The
+	// fee payer's change has the transaction fee added back.
+	async function fetchTransaction(signature: string) {
+		const tx = await connection.getTransaction(signature, {
+			commitment: 'confirmed',
+			maxSupportedTransactionVersion: 0,
+		});
+		const accountKeys = tx.transaction.message.getAccountKeys().staticAccountKeys;
+		const events = [...eventParser.parseLogs(tx.meta.logMessages)];
+
+		return {
+			event(name: string) {
+				const event = events.find((event) => event.name === name);
+				expect(event, `${name} event`).to.exist;
+				return event.data as Record<string, any>;
+			},
+			lamportChange(account: PublicKey) {
+				const index = accountKeys.findIndex((key) => key.equals(account));
+				expect(index, `${account.toBase58()} in transaction`).to.be.at.least(0);
+				const change = tx.meta.postBalances[index] - tx.meta.preBalances[index];
+				return index === 0 ? change + tx.meta.fee : change;
+			},
+		};
+	}
+
+	// Launches a curve migrating to `migrationTarget` and buys it out, leaving it `complete`
+	async function launchCompletedCurve(
+		migrationTarget: Parameters<typeof program.methods.initialize>[5]
+	) {
+		// Enough to buy out a default curve in one go
+		await connection.confirmTransaction(
+			await connection.requestAirdrop(creator.publicKey, 150 * LAMPORTS_PER_SOL),
+			'confirmed'
+		);
+
+		const curveMint = Keypair.generate();
+		await program.methods
+			.initialize(
+				'Solana Gold',
+				'GOLDSOL',
+				'https://53cso10vyy.ufs.sh/f/0zLYHmgdOsEGYF3WHmI7jv08b2BZmzpuEFaAiQNHXKsgrPTD',
+				null,
+				{ constantProduct: {} },
+				migrationTarget
+			)
+			.accounts({
+				creator: creator.publicKey,
+				mint: curveMint.publicKey,
+				tokenMetadataProgram: new PublicKey(
+					'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+				),
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.preInstructions([
+				SystemProgram.createAccount({
+					fromPubkey: creator.publicKey,
+					newAccountPubkey: curveMint.publicKey,
+					space: MINT_SIZE,
+					lamports: await getMinimumBalanceForRentExemptMint(connection),
+					programId: TOKEN_PROGRAM_ID,
+				}),
+				createInitializeMintInstruction(
+					curveMint.publicKey,
+					6,
+					pda('mint_authority'),
+					null,
+					TOKEN_PROGRAM_ID
+				),
+			])
+			.signers([creator, curveMint])
+			.rpc({ commitment: 'confirmed' });
+
+		// The buy is partially filled at graduation and the rest refunded
+		await program.methods
+			.buy(new BN(120 * LAMPORTS_PER_SOL), null, null)
+			.accounts({
+				mint: curveMint.publicKey,
+				buyer: creator.publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				referrer: null,
+				referrerStats: null,
+				buyerRecord: null,
+				priceOracle: null,
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
+
+		const bondingCurve = await program.account.bondingCurve.fetch(
+			pda('bonding_curve', curveMint.publicKey)
+		);
+		expect(bondingCurve.status).to.deep.equal({ complete: {} });
+
+		return curveMint;
+	}
+
+	// Checks a curve handed all of its SOL and tokens to its pool
+	async function expectMigrated(curveMint: PublicKey) {
+		const bondingCurve = await program.account.bondingCurve.fetch(
+			pda('bonding_curve', curveMint)
+		);
+		expect(bondingCurve.status).to.deep.equal({ migrated: {} });
+		expect(bondingCurve.realSolReserves.toNumber()).to.equal(0);
+		expect(bondingCurve.realTokenReserves.toNumber()).to.equal(0);
+		expect(
+			(await connection.getTokenAccountBalance(
+				getAssociatedTokenAddressSync(curveMint, pda('bonding_curve', curveMint), true)
+			)).value.amount
+		).to.equal('0');
+	}
+
+	// Checks a pool's lamports above rent and its token account match its recorded reserves
+	async function expectPoolBalances(pool: PublicKey) {
+		const poolAccount = await program.account.pool.fetch(pool);
+		const { lamports, data } = await connection.getAccountInfo(pool);
+		const rent = await connection.getMinimumBalanceForRentExemption(data.length);
+
+		expect(lamports - rent).to.equal(poolAccount.solReserves.toNumber());
+		expect(
+			(await connection.getTokenAccountBalance(
+				getAssociatedTokenAddressSync(poolAccount.mint, pool, true)
+			)).value.amount
+		).to.equal(poolAccount.tokenReserves.toString());
+	}
+
+	// Integer square root, as `PoolState::new` sizes a pool's initial LP supply
+	function isqrt(value: BN) {
+		if (value.ltn(2)) {
+			return value;
+		}
+		let root = value;
+		let next = value.addn(1).shrn(1);
+		while (next.lt(root)) {
+			root = next;
+			next = root.add(value.div(root)).shrn(1);
+		}
+		return root;
+	}
+
+	const divCeil = (numerator: BN, denominator: BN) =>
+		numerator.add(denominator).subn(1).div(denominator);
+
+	// Raydium's LP mint and the migration authority's LP token account for a curve's pool
+	function raydiumLpAccounts(curveMint: PublicKey) {
+		const [pool] = PublicKey.findProgramAddressSync(
+			[
+				Buffer.from('pool'),
+				raydiumAmmConfig.toBuffer(),
+				NATIVE_MINT.toBuffer(),
+				curveMint.toBuffer(),
+			],
+			raydiumProgram
+		);
+		const [lpMint] = PublicKey.findProgramAddressSync(
+			[Buffer.from('pool_lp_mint'), pool.toBuffer()],
+			raydiumProgram
+		);
+		const creatorLpToken = getAssociatedTokenAddressSync(
+			lpMint,
+			pda('migration_authority', curveMint),
+			true,
+			TOKEN_PROGRAM_ID
+		);
+
+		return { lpMint, creatorLpToken };
+	}
+
+	it('should initialize global config', async () => {
+		const [programData] = PublicKey.findProgramAddressSync(
+			[program.programId.toBuffer()],
+			new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111')
+		);
+
+		const tx = await program.methods
+			.initializeConfig()
+			.accounts({
+				authority: creator.publicKey,
+				programData,
+			})
+			.signers([creator])
+			.rpc({ skipPreflight: false });
+
+		console.log('Initialize config transaction:', tx);
+	});
+
+	it('should update global config', async () => {
+		const tx = await program.methods
+			.updateConfig({
+				newAuthority: null,
+				feeSchedule: {
+					startFeeBps: new BN(500),
+					endFeeBps: new BN(100),
+					taperProgressBps: new BN(2_000),
+				},
+				launchWindow: null,
+				graduationTrigger: null,
+				priceOracle: null,
+				oracleMaxAgeSecs: null,
+				oracleMaxConfidenceBps: null,
+				creatorFeeShareBps: null,
+				referralFeeShareBps: null,
+				tokenInitialisationFee: null,
+				migrationFee: null,
+				migrationReward: null,
+				lpDisposition: null,
+				meteoraPoolConfig: null,
+				initialVirtualSolReserves: null,
+				initialVirtualTokenReserves: null,
+				initialRealTokenReserves: null,
+				tokenTotalSupply: null,
+			})
+			.accounts({
+				authority: creator.publicKey,
+			})
+			.signers([creator])
+			.rpc({ skipPreflight: false });
+
+		console.log('Update config transaction:', tx);
+	});
 
 	it('should initialize mint and bonding curve', async () => {
 		const [mintAuthorityPDA] = PublicKey.findProgramAddressSync(
@@ -57,7 +309,10 @@ describe('rekthub', () => {
 			.initialize(
 				'Solana Gold',
 				'GOLDSOL',
-				'https://53cso10vyy.ufs.sh/f/0zLYHmgdOsEGYF3WHmI7jv08b2BZmzpuEFaAiQNHXKsgrPTD'
+				'https://53cso10vyy.ufs.sh/f/0zLYHmgdOsEGYF3WHmI7jv08b2BZmzpuEFaAiQNHXKsgrPTD',
+				null,
+				{ constantProduct: {} },
+				{ raydiumCpmm: {} }
 			)
 			.accounts({
 				creator: creator.publicKey,
@@ -74,95 +329,390 @@ describe('rekthub', () => {
 		console.log('Initialize transaction:', tx);
 	});
 
+	it('should register a referrer', async () => {
+		const tx = await program.methods
+			.registerReferrer()
+			.accounts({
+				referrer: creator.publicKey,
+			})
+			.signers([creator])
+			.rpc({ skipPreflight: false });
+
+		console.log('Register referrer transaction:', tx);
+	});
+
+	it('should reject a referral fee claim with nothing accrued', async () => {
+		try {
+			await program.methods
+				.claimReferralFees()
+				.accounts({
+					referrer: creator.publicKey,
+				})
+				.signers([creator])
+				.rpc({ skipPreflight: false });
+		} catch (err) {
+			console.log('Claim referral fees rejected:', err.error?.errorCode?.code);
+			return;
+		}
+		throw new Error('Claim with no accrued referral fees should fail');
+	});
+
+	it('should quote a buy without sending a transaction', async () => {
+		const quote = await program.methods
+			.quoteBuy(new BN(5 * LAMPORTS_PER_SOL), false)
+			.accounts({
+				mint: mint.publicKey,
+				priceOracle: null,
+			})
+			.view();
+
+		console.log('Buy quote:', quote.tokenAmount.toString(), quote.fees.fee.toString());
+	});
+
+	it('should quote a buy checked against the SOL/USD price oracle', async function () {
+		// Loaded from tests/fixtures on a local validator
+		const solUsdPriceFeed = new PublicKey('J83w4HKfqxwcq3BEMMkPFSppX3gqekLyLJBexebFVkix');
+		if (!(await connection.getAccountInfo(solUsdPriceFeed))) {
+			this.skip();
+		}
+
+		await program.methods
+			.updateConfig({
+				newAuthority: null,
+				feeSchedule: null,
+				launchWindow: null,
+				graduationTrigger: null,
+				priceOracle: solUsdPriceFeed,
+				// The fixture's publish time is fixed
+				oracleMaxAgeSecs: new BN(10 * 365 * 24 * 60 * 60),
+				oracleMaxConfidenceBps: null,
+				creatorFeeShareBps: null,
+				referralFeeShareBps: null,
+				tokenInitialisationFee: null,
+				migrationFee: null,
+				migrationReward: null,
+				lpDisposition: null,
+				meteoraPoolConfig: null,
+				initialVirtualSolReserves: null,
+				initialVirtualTokenReserves: null,
+				initialRealTokenReserves: null,
+				tokenTotalSupply: null,
+			})
+			.accounts({
+				authority: creator.publicKey,
+			})
+			.signers([creator])
+			.rpc({ skipPreflight: false });
+
+		const quote = await program.methods
+			.quoteBuy(new BN(5 * LAMPORTS_PER_SOL), false)
+			.accounts({
+				mint: mint.publicKey,
+				priceOracle: solUsdPriceFeed,
+			})
+			.view();
+
+		console.log('Oracle-checked buy quote:', quote.tokenAmount.toString());
+	});
+
 	it('should buy tokens from bonding curve', async () => {
+		await connection.confirmTransaction(
+			await connection.requestAirdrop(trader.publicKey, 10 * LAMPORTS_PER_SOL),
+			'confirmed'
+		);
+
 		const tx = await program.methods
-			.buy(new BN(5 * LAMPORTS_PER_SOL), new BN(50))
+			.buy(
+				new BN(5 * LAMPORTS_PER_SOL),
+				new BN(100_000_000 * 1_000_000),
+				{ unixTimestamp: { 0: new BN(Math.floor(Date.now() / 1000) + 60) } }
+			)
+			.accounts({
+				mint: mint.publicKey,
+				buyer: trader.publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				referrer: creator.publicKey,
+				referrerStats: referrerStats,
+				buyerRecord: null,
+				priceOracle: null,
+			})
+			.signers([trader])
+			.rpc({ commitment: 'confirmed' });
+
+		const { event, lamportChange } = await fetchTransaction(tx);
+		const buy = event('onBuyEvent');
+		const solSpent = buy.solSpent.toNumber();
+		const feePaid = buy.feePaid.toNumber();
+		const creatorFee = buy.creatorFee.toNumber();
+		const referralFee = buy.referralFee.toNumber();
+		referralFeesAccrued += referralFee;
+
+		expect(creatorFee).to.be.greaterThan(0);
+		expect(referralFee).to.be.greaterThan(0);
+		// The trader also pays rent for their new token account
+		expect(lamportChange(trader.publicKey)).to.equal(
+			-solSpent - (await getMinimumBalanceForRentExemptAccount(connection))
+		);
+		expect(lamportChange(pda('bonding_curve', mint.publicKey))).to.equal(solSpent - feePaid);
+		expect(lamportChange(globalFeeVault)).to.equal(feePaid - creatorFee - referralFee);
+		expect(lamportChange(pda('creator_fee_vault', mint.publicKey))).to.equal(creatorFee);
+		expect(lamportChange(referrerStats)).to.equal(referralFee);
+	});
+
+	it('should buy an exact amount of tokens from bonding curve', async () => {
+		const tx = await program.methods
+			.buyExactTokens(
+				new BN(1_000_000).mul(new BN(1_000_000)),
+				new BN(LAMPORTS_PER_SOL),
+				null
+			)
 			.accounts({
 				mint: mint.publicKey,
 				buyer: creator.publicKey,
 				tokenProgram: TOKEN_PROGRAM_ID,
+				referrer: null,
+				referrerStats: null,
+				buyerRecord: null,
+				priceOracle: null,
 			})
 			.signers([creator])
 			.rpc({ skipPreflight: false });
 
-		console.log('Buy transaction:', tx);
+		console.log('Buy exact tokens transaction:', tx);
 	});
 
-	it('should sell 100% of tokens to bonding curve', async () => {
+	it('should sell tokens for an exact amount of SOL', async () => {
 		const tx = await program.methods
-			.sell(new BN(10_000), true, new BN(50))
+			.sellForExactSol(
+				new BN(LAMPORTS_PER_SOL / 10),
+				new BN(10_000_000).mul(new BN(1_000_000)),
+				null
+			)
 			.accounts({
 				mint: mint.publicKey,
 				seller: creator.publicKey,
 				tokenProgram: TOKEN_PROGRAM_ID,
+				referrer: null,
+				referrerStats: null,
 			})
 			.signers([creator])
-			.rpc({ skipPreflight: true });
+			.rpc({ skipPreflight: false });
 
-		console.log('Sell transaction:', tx);
+		console.log('Sell for exact SOL transaction:', tx);
 	});
 
-	it('should prepare bonding curve for migration', async () => {
+	it('should sell 100% of tokens to bonding curve', async () => {
 		const tx = await program.methods
-			.prepareCurveMigration()
+			.sell(new BN(10_000), true, new BN(4 * LAMPORTS_PER_SOL), null)
 			.accounts({
-				signer: creator.publicKey,
 				mint: mint.publicKey,
+				seller: trader.publicKey,
 				tokenProgram: TOKEN_PROGRAM_ID,
+				referrer: creator.publicKey,
+				referrerStats: referrerStats,
+			})
+			.signers([trader])
+			.rpc({ commitment: 'confirmed' });
+
+		const { event, lamportChange } = await fetchTransaction(tx);
+		const sell = event('onSellEvent');
+		const solReceived = sell.solReceived.toNumber();
+		const feePaid = sell.feePaid.toNumber();
+		const creatorFee = sell.creatorFee.toNumber();
+		const referralFee = sell.referralFee.toNumber();
+		referralFeesAccrued += referralFee;
+
+		expect(referralFee).to.be.greaterThan(0);
+		expect(lamportChange(trader.publicKey)).to.equal(solReceived);
+		expect(lamportChange(pda('bonding_curve', mint.publicKey))).to.equal(
+			-solReceived - feePaid
+		);
+		expect(lamportChange(globalFeeVault)).to.equal(feePaid - creatorFee - referralFee);
+		expect(lamportChange(pda('creator_fee_vault', mint.publicKey))).to.equal(creatorFee);
+		expect(lamportChange(referrerStats)).to.equal(referralFee);
+		expect(
+			(await connection.getTokenAccountBalance(
+				getAssociatedTokenAddressSync(mint.publicKey, trader.publicKey)
+			)).value.amount
+		).to.equal('0');
+	});
+
+	it('should claim creator trading fees', async () => {
+		const creatorFeeVault = pda('creator_fee_vault', mint.publicKey);
+		const claimable =
+			(await connection.getBalance(creatorFeeVault)) -
+			(await connection.getMinimumBalanceForRentExemption(0));
+
+		const tx = await program.methods
+			.claimCreatorFees()
+			.accounts({
+				creator: creator.publicKey,
+				mint: mint.publicKey,
 			})
 			.signers([creator])
-			.rpc({ skipPreflight: true });
+			.rpc({ commitment: 'confirmed' });
 
-		console.log('Migration preparation transaction:', tx);
+		const { event, lamportChange } = await fetchTransaction(tx);
+		const amount = event('creatorFeesClaimed').amount.toNumber();
+
+		expect(amount).to.equal(claimable);
+		expect(lamportChange(creator.publicKey)).to.equal(amount);
+		expect(lamportChange(creatorFeeVault)).to.equal(-amount);
 	});
 
-	it('should initialize Raydium pool with migration authority funds', async () => {
-		function u16ToBytes(num: number) {
-			const arr = new ArrayBuffer(2);
-			const view = new DataView(arr);
-			view.setUint16(0, num, true);
-			return new Uint8Array(arr);
-		}
+	it('should claim accrued referral fees', async () => {
+		const tx = await program.methods
+			.claimReferralFees()
+			.accounts({
+				referrer: creator.publicKey,
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
 
-		const [ammConfig] = await PublicKey.findProgramAddress(
-			[Buffer.from('amm_config'), u16ToBytes(0)],
-			new PublicKey('DRaycpLY18LhpbydsBWbVJtxpNv9oXPgjRSfpF2bWpYb')
-		);
+		const { event, lamportChange } = await fetchTransaction(tx);
+		const amount = event('referralFeesClaimed').amount.toNumber();
 
-		const [pool] = await PublicKey.findProgramAddress(
-			[
-				Buffer.from('pool'),
-				ammConfig.toBuffer(),
-				NATIVE_MINT.toBuffer(),
-				mint.publicKey.toBuffer(),
-			],
-			new PublicKey('DRaycpLY18LhpbydsBWbVJtxpNv9oXPgjRSfpF2bWpYb')
+		expect(amount).to.equal(referralFeesAccrued);
+		expect(lamportChange(creator.publicKey)).to.equal(amount);
+		expect(lamportChange(referrerStats)).to.equal(-amount);
+	});
+
+	it('should withdraw accumulated protocol fees', async () => {
+		const tx = await program.methods
+			.withdrawFees(null)
+			.accounts({
+				authority: creator.publicKey,
+				recipient: creator.publicKey,
+			})
+			.signers([creator])
+			.rpc({ skipPreflight: false });
+
+		console.log('Withdraw fees transaction:', tx);
+	});
+
+	it('should prepare a completed curve for a Raydium migration', async function () {
+		// The Raydium program and AMM config are loaded from tests/fixtures on a local validator
+		if (!(await connection.getAccountInfo(raydiumAmmConfig))) {
+			this.skip();
+		}
+		raydiumMint = await launchCompletedCurve({ raydiumCpmm: {} });
+		const migrationAuthority = pda('migration_authority', raydiumMint.publicKey);
+		const curveTokens = getAssociatedTokenAddressSync(
+			raydiumMint.publicKey,
+			pda('bonding_curve', raydiumMint.publicKey),
+			true
 		);
+		const curveTokenAmount = (await connection.getTokenAccountBalance(curveTokens)).value
+			.amount;
 
-		const [lpMintAddress] = await PublicKey.findProgramAddress(
-			[Buffer.from('pool_lp_mint'), pool.toBuffer()],
-			new PublicKey('DRaycpLY18LhpbydsBWbVJtxpNv9oXPgjRSfpF2bWpYb')
+		const tx = await program.methods
+			.prepareCurveMigration()
+			.accounts({
+				signer: creator.publicKey,
+				mint: raydiumMint.publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
+
+		const { event, lamportChange } = await fetchTransaction(tx);
+		const prepared = event('curveMigrationPrepared');
+		const { migrationFee } = await program.account.bondingCurve.fetch(
+			pda('bonding_curve', raydiumMint.publicKey)
 		);
+		raydiumPreparedSol = prepared.solAmount.sub(migrationFee).toNumber();
 
-		const creatorLpToken = getAssociatedTokenAddressSync(
-			lpMintAddress,
-			PublicKey.findProgramAddressSync(
-				[Buffer.from('migration_authority'), mint.publicKey.toBuffer()],
-				program.programId
-			)[0],
-			true,
-			TOKEN_PROGRAM_ID
+		expect(lamportChange(migrationAuthority)).to.equal(raydiumPreparedSol);
+		expect(lamportChange(globalFeeVault)).to.equal(migrationFee.toNumber());
+		expect(
+			(await connection.getTokenAccountBalance(
+				getAssociatedTokenAddressSync(raydiumMint.publicKey, migrationAuthority, true)
+			)).value.amount
+		).to.equal(curveTokenAmount);
+
+		const bondingCurve = await program.account.bondingCurve.fetch(
+			pda('bonding_curve', raydiumMint.publicKey)
 		);
+		expect(bondingCurve.status).to.deep.equal({ migrationPrepared: {} });
+		expect(bondingCurve.realSolReserves.toNumber()).to.equal(0);
+		expect(bondingCurve.realTokenReserves.toNumber()).to.equal(0);
+	});
+
+	it('should initialize Raydium pool with migration authority funds', async function () {
+		if (!raydiumMint) {
+			this.skip();
+		}
+		const { lpMint, creatorLpToken } = raydiumLpAccounts(raydiumMint.publicKey);
+		const tokenAmount = (await connection.getTokenAccountBalance(
+			getAssociatedTokenAddressSync(
+				raydiumMint.publicKey,
+				pda('migration_authority', raydiumMint.publicKey),
+				true
+			)
+		)).value.amount;
 
 		const tx = await program.methods
 			.initializeRaydiumPool()
 			.accounts({
 				signer: creator.publicKey,
 				mint0: NATIVE_MINT,
-				mint1: mint.publicKey,
+				mint1: raydiumMint.publicKey,
 				mint0Program: TOKEN_PROGRAM_ID,
 				mint1Program: TOKEN_PROGRAM_ID,
-				lpMint: lpMintAddress,
+				lpMint,
+				creatorLpToken,
+				lpLock: null,
+				lpLockToken: null,
+			})
+			.signers([creator])
+			.preInstructions([
+				ComputeBudgetProgram.setComputeUnitLimit({
+					units: 400_000,
+				}),
+			])
+			.rpc({ commitment: 'confirmed' });
+
+		const { event } = await fetchTransaction(tx);
+		const initialized = event('raydiumPoolInitialized');
+
+		expect(initialized.initialToken0Amount.toNumber()).to.equal(
+			raydiumPreparedSol - raydiumPoolCreationReserve
+		);
+		expect(initialized.initialToken1Amount.toString()).to.equal(tokenAmount);
+		expect(initialized.lpTokensMinted.toNumber()).to.be.greaterThan(0);
+		expect(initialized.lpDisposition).to.deep.equal({ burn: {} });
+		// The default `LpDisposition::Burn` leaves no LP behind
+		expect((await connection.getTokenAccountBalance(creatorLpToken)).value.amount).to.equal(
+			'0'
+		);
+		await expectMigrated(raydiumMint.publicKey);
+	});
+
+	it('should migrate a completed curve to Raydium in one transaction', async function () {
+		if (!(await connection.getAccountInfo(raydiumAmmConfig))) {
+			this.skip();
+		}
+		const curveMint = await launchCompletedCurve({ raydiumCpmm: {} });
+		const bondingCurve = pda('bonding_curve', curveMint.publicKey);
+		const completed = await program.account.bondingCurve.fetch(bondingCurve);
+		const tokenAmount = (await connection.getTokenAccountBalance(
+			getAssociatedTokenAddressSync(curveMint.publicKey, bondingCurve, true)
+		)).value.amount;
+		const { lpMint, creatorLpToken } = raydiumLpAccounts(curveMint.publicKey);
+
+		const tx = await program.methods
+			.migrate()
+			.accounts({
+				curve: {
+					signer: creator.publicKey,
+					mint0: NATIVE_MINT,
+					mint1: curveMint.publicKey,
+					mint0Program: TOKEN_PROGRAM_ID,
+					mint1Program: TOKEN_PROGRAM_ID,
+					lpLock: null,
+					lpLockToken: null,
+				},
+				lpMint,
 				creatorLpToken,
 			})
 			.signers([creator])
@@ -171,8 +721,331 @@ describe('rekthub', () => {
 					units: 400_000,
 				}),
 			])
+			.rpc({ commitment: 'confirmed' });
+
+		const { event } = await fetchTransaction(tx);
+		const migrated = event('curveMigrated');
+
+		expect(migrated.migrationTarget).to.deep.equal({ raydiumCpmm: {} });
+		expect(migrated.cranker.equals(creator.publicKey)).to.be.true;
+		expect(migrated.solAmount.toNumber()).to.equal(
+			completed.realSolReserves.sub(completed.migrationFee).toNumber() -
+				raydiumPoolCreationReserve
+		);
+		expect(migrated.tokenAmount.toString()).to.equal(tokenAmount);
+		expect(migrated.migrationFee.eq(completed.migrationFee)).to.be.true;
+		expect(migrated.crankerReward.eq(completed.migrationReward)).to.be.true;
+		expect(migrated.lpTokensMinted.toNumber()).to.be.greaterThan(0);
+		expect((await connection.getTokenAccountBalance(creatorLpToken)).value.amount).to.equal(
+			'0'
+		);
+		await expectMigrated(curveMint.publicKey);
+	});
+
+	it('should migrate a completed curve to Meteora DAMM in one transaction', async function () {
+		// The DAMM and vault programs and the DAMM pool config are loaded from tests/fixtures on a
+		// local validator
+		const dammProgram = new PublicKey('Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB');
+		const vaultProgram = new PublicKey('24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi');
+		const vaultBase = new PublicKey('HWzXGcGHy4tcpYfaRDCyLNzXqBTv3E6BttpCH2vJxArv');
+		const metadataProgram = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+		const poolConfig = new PublicKey('6E6rYrvfxMDPfu3e7bjbRZNrqxkuU9Bkbcv65SpmMwgt');
+		if (!(await connection.getAccountInfo(dammProgram))) {
+			this.skip();
+		}
+
+		await program.methods
+			.updateConfig({
+				newAuthority: null,
+				feeSchedule: null,
+				launchWindow: null,
+				graduationTrigger: null,
+				priceOracle: null,
+				oracleMaxAgeSecs: null,
+				oracleMaxConfidenceBps: null,
+				creatorFeeShareBps: null,
+				referralFeeShareBps: null,
+				tokenInitialisationFee: null,
+				migrationFee: null,
+				migrationReward: null,
+				lpDisposition: null,
+				meteoraPoolConfig: poolConfig,
+				initialVirtualSolReserves: null,
+				initialVirtualTokenReserves: null,
+				initialRealTokenReserves: null,
+				tokenTotalSupply: null,
+			})
+			.accounts({
+				authority: creator.publicKey,
+			})
+			.signers([creator])
+			.rpc({ skipPreflight: false });
+
+		const meteoraMint = await launchCompletedCurve({ meteoraDamm: {} });
+
+		const [pool] = PublicKey.findProgramAddressSync(
+			[meteoraMint.publicKey.toBuffer(), NATIVE_MINT.toBuffer(), poolConfig.toBuffer()],
+			dammProgram
+		);
+		const [lpMint] = PublicKey.findProgramAddressSync(
+			[Buffer.from('lp_mint'), pool.toBuffer()],
+			dammProgram
+		);
+		const [aVault] = PublicKey.findProgramAddressSync(
+			[Buffer.from('vault'), meteoraMint.publicKey.toBuffer(), vaultBase.toBuffer()],
+			vaultProgram
+		);
+		const [bVault] = PublicKey.findProgramAddressSync(
+			[Buffer.from('vault'), NATIVE_MINT.toBuffer(), vaultBase.toBuffer()],
+			vaultProgram
+		);
+		const [migrationAuthority] = PublicKey.findProgramAddressSync(
+			[Buffer.from('migration_authority'), meteoraMint.publicKey.toBuffer()],
+			program.programId
+		);
+
+		// Neither vault exists yet, so `migrate_meteora` creates both before the pool
+		await program.methods
+			.migrateMeteora()
+			.accounts({
+				curve: {
+					signer: creator.publicKey,
+					mint0: NATIVE_MINT,
+					mint1: meteoraMint.publicKey,
+					mint0Program: TOKEN_PROGRAM_ID,
+					mint1Program: TOKEN_PROGRAM_ID,
+					lpLock: null,
+					lpLockToken: null,
+				},
+				poolConfig,
+				pool,
+				lpMint,
+				payerPoolLp: getAssociatedTokenAddressSync(
+					lpMint,
+					migrationAuthority,
+					true,
+					TOKEN_PROGRAM_ID
+				),
+				aVault,
+				bVault,
+				mintMetadata: PublicKey.findProgramAddressSync(
+					[Buffer.from('metadata'), metadataProgram.toBuffer(), lpMint.toBuffer()],
+					metadataProgram
+				)[0],
+			})
+			.signers([creator])
+			.preInstructions([
+				ComputeBudgetProgram.setComputeUnitLimit({
+					units: 1_000_000,
+				}),
+			])
 			.rpc({ skipPreflight: true });
 
-		console.log('Raydium pool initialization transaction:', tx);
+		await expectMigrated(meteoraMint.publicKey);
+	});
+
+	it('should migrate a completed curve to its in-house pool', async () => {
+		poolMint = await launchCompletedCurve({ rekthubPool: {} });
+		const bondingCurve = pda('bonding_curve', poolMint.publicKey);
+		const completed = await program.account.bondingCurve.fetch(bondingCurve);
+		const tokenAmount = new BN(
+			(await connection.getTokenAccountBalance(
+				getAssociatedTokenAddressSync(poolMint.publicKey, bondingCurve, true)
+			)).value.amount
+		);
+
+		const tx = await program.methods
+			.migrateToPool()
+			.accounts({
+				curve: {
+					signer: creator.publicKey,
+					mint0: NATIVE_MINT,
+					mint1: poolMint.publicKey,
+					mint0Program: TOKEN_PROGRAM_ID,
+					mint1Program: TOKEN_PROGRAM_ID,
+					lpLock: null,
+					lpLockToken: null,
+				},
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
+
+		const { event } = await fetchTransaction(tx);
+		const migrated = event('curveMigrated');
+		const pool = pda('pool', poolMint.publicKey);
+		const poolAccount = await program.account.pool.fetch(pool);
+		// `RekthubPool` holds nothing back for pool creation
+		const solAmount = completed.realSolReserves.sub(completed.migrationFee);
+		const lpSupply = isqrt(solAmount.mul(tokenAmount));
+
+		expect(migrated.migrationTarget).to.deep.equal({ rekthubPool: {} });
+		expect(migrated.solAmount.eq(solAmount)).to.be.true;
+		expect(migrated.tokenAmount.eq(tokenAmount)).to.be.true;
+		expect(poolAccount.solReserves.eq(solAmount)).to.be.true;
+		expect(poolAccount.tokenReserves.eq(tokenAmount)).to.be.true;
+		expect(poolAccount.feeBps.toNumber()).to.equal(poolFeeBps);
+		expect(poolAccount.lpSupply.eq(lpSupply)).to.be.true;
+		// The first `MINIMUM_LIQUIDITY` LP tokens are never minted and the rest are burned by default
+		expect(migrated.lpTokensMinted.eq(lpSupply.subn(minimumLiquidity))).to.be.true;
+		expect((await connection.getTokenSupply(poolAccount.lpMint)).value.amount).to.equal('0');
+		await expectPoolBalances(pool);
+		await expectMigrated(poolMint.publicKey);
+	});
+
+	it('should swap against the in-house pool', async function () {
+		if (!poolMint) {
+			this.skip();
+		}
+		const pool = pda('pool', poolMint.publicKey);
+		const before = await program.account.pool.fetch(pool);
+
+		const buyQuote = await program.methods
+			.quoteSwap(new BN(LAMPORTS_PER_SOL), { buy: {} })
+			.accounts({ mint: poolMint.publicKey })
+			.view();
+
+		const buyTx = await program.methods
+			.swap(new BN(LAMPORTS_PER_SOL), buyQuote.amountOut, { buy: {} }, null)
+			.accounts({
+				user: creator.publicKey,
+				mint: poolMint.publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
+
+		const bought = await fetchTransaction(buyTx);
+		const buy = bought.event('poolSwapped');
+		const afterBuy = await program.account.pool.fetch(pool);
+
+		expect(buy.amountIn.eq(buyQuote.amountIn)).to.be.true;
+		expect(buy.amountOut.eq(buyQuote.amountOut)).to.be.true;
+		expect(buy.fee.eq(buyQuote.fee)).to.be.true;
+		expect(buyQuote.fee.toNumber()).to.be.greaterThan(0);
+		expect(bought.lamportChange(pool)).to.equal(buyQuote.amountIn.toNumber());
+		expect(afterBuy.solReserves.eq(before.solReserves.add(buyQuote.amountIn))).to.be.true;
+		expect(afterBuy.tokenReserves.eq(before.tokenReserves.sub(buyQuote.amountOut))).to.be.true;
+		// The fee stays in the pool, so the constant product grows
+		expect(
+			afterBuy.solReserves
+				.mul(afterBuy.tokenReserves)
+				.gt(before.solReserves.mul(before.tokenReserves))
+		).to.be.true;
+		await expectPoolBalances(pool);
+
+		const sellQuote = await program.methods
+			.quoteSwap(buyQuote.amountOut, { sell: {} })
+			.accounts({ mint: poolMint.publicKey })
+			.view();
+
+		const sellTx = await program.methods
+			.swap(buyQuote.amountOut, sellQuote.amountOut, { sell: {} }, null)
+			.accounts({
+				user: creator.publicKey,
+				mint: poolMint.publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
+
+		const sold = await fetchTransaction(sellTx);
+		const sell = sold.event('poolSwapped');
+		const afterSell = await program.account.pool.fetch(pool);
+
+		expect(sell.amountIn.eq(buyQuote.amountOut)).to.be.true;
+		expect(sell.amountOut.eq(sellQuote.amountOut)).to.be.true;
+		expect(sell.fee.eq(sellQuote.fee)).to.be.true;
+		expect(sellQuote.amountOut.lt(buyQuote.amountIn)).to.be.true;
+		expect(sold.lamportChange(pool)).to.equal(-sellQuote.amountOut.toNumber());
+		expect(afterSell.solReserves.eq(afterBuy.solReserves.sub(sellQuote.amountOut))).to.be.true;
+		expect(afterSell.tokenReserves.eq(before.tokenReserves)).to.be.true;
+		await expectPoolBalances(pool);
+	});
+
+	it('should add and remove in-house pool liquidity', async function () {
+		if (!poolMint) {
+			this.skip();
+		}
+		const pool = pda('pool', poolMint.publicKey);
+		const before = await program.account.pool.fetch(pool);
+		const providerLpToken = getAssociatedTokenAddressSync(before.lpMint, creator.publicKey);
+		const deadline = {
+			unixTimestamp: { 0: new BN(Math.floor(Date.now() / 1000) + 60) },
+		};
+
+		// Offer 1 SOL and a proportional amount of tokens, with 1% headroom for rounding
+		const maxSolAmount = new BN(LAMPORTS_PER_SOL);
+		const maxTokenAmount = maxSolAmount
+			.mul(before.tokenReserves)
+			.div(before.solReserves)
+			.muln(101)
+			.divn(100);
+		// `PoolState::quote_deposit` rounds the LP down and the deposit up
+		const lpAmount = BN.min(
+			maxSolAmount.mul(before.lpSupply).div(before.solReserves),
+			maxTokenAmount.mul(before.lpSupply).div(before.tokenReserves)
+		);
+		const solDeposit = divCeil(lpAmount.mul(before.solReserves), before.lpSupply);
+		const tokenDeposit = divCeil(lpAmount.mul(before.tokenReserves), before.lpSupply);
+
+		const addTx = await program.methods
+			.addLiquidity(maxSolAmount, maxTokenAmount, lpAmount, deadline)
+			.accounts({
+				provider: creator.publicKey,
+				mint: poolMint.publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
+
+		const added = await fetchTransaction(addTx);
+		const deposit = added.event('liquidityAdded');
+		const afterAdd = await program.account.pool.fetch(pool);
+
+		expect(deposit.lpAmount.eq(lpAmount)).to.be.true;
+		expect(deposit.solAmount.eq(solDeposit)).to.be.true;
+		expect(deposit.tokenAmount.eq(tokenDeposit)).to.be.true;
+		expect(added.lamportChange(pool)).to.equal(solDeposit.toNumber());
+		expect(afterAdd.solReserves.eq(before.solReserves.add(solDeposit))).to.be.true;
+		expect(afterAdd.tokenReserves.eq(before.tokenReserves.add(tokenDeposit))).to.be.true;
+		expect(afterAdd.lpSupply.eq(before.lpSupply.add(lpAmount))).to.be.true;
+		expect((await connection.getTokenAccountBalance(providerLpToken)).value.amount).to.equal(
+			lpAmount.toString()
+		);
+		// The migration's LP was burned, so the provider holds every minted LP token
+		expect((await connection.getTokenSupply(before.lpMint)).value.amount).to.equal(
+			lpAmount.toString()
+		);
+		await expectPoolBalances(pool);
+
+		// `PoolState::quote_withdraw` rounds both amounts down
+		const solWithdrawal = lpAmount.mul(afterAdd.solReserves).div(afterAdd.lpSupply);
+		const tokenWithdrawal = lpAmount.mul(afterAdd.tokenReserves).div(afterAdd.lpSupply);
+
+		const removeTx = await program.methods
+			.removeLiquidity(lpAmount, solWithdrawal, tokenWithdrawal, deadline)
+			.accounts({
+				provider: creator.publicKey,
+				mint: poolMint.publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			})
+			.signers([creator])
+			.rpc({ commitment: 'confirmed' });
+
+		const removed = await fetchTransaction(removeTx);
+		const withdrawal = removed.event('liquidityRemoved');
+		const afterRemove = await program.account.pool.fetch(pool);
+
+		expect(withdrawal.lpAmount.eq(lpAmount)).to.be.true;
+		expect(withdrawal.solAmount.eq(solWithdrawal)).to.be.true;
+		expect(withdrawal.tokenAmount.eq(tokenWithdrawal)).to.be.true;
+		expect(removed.lamportChange(pool)).to.equal(-solWithdrawal.toNumber());
+		expect(afterRemove.lpSupply.eq(before.lpSupply)).to.be.true;
+		expect(afterRemove.solReserves.eq(afterAdd.solReserves.sub(solWithdrawal))).to.be.true;
+		expect(
+			afterRemove.tokenReserves.eq(afterAdd.tokenReserves.sub(tokenWithdrawal))
+		).to.be.true;
+		expect((await connection.getTokenSupply(before.lpMint)).value.amount).to.equal('0');
+		await expectPoolBalances(pool);
 	});
 });